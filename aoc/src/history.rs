@@ -0,0 +1,248 @@
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    num::ParseIntError,
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use problem::{PartOutcome, SolveReport, input_name};
+
+pub const HISTORY_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc-history.tsv");
+
+pub const MACHINE_ENV_VAR: &str = "AOC_MACHINE";
+
+const HEADER: &str = "timestamp_ms\tcommit\tmachine\tday\tinput\tphase\telapsed_ns";
+
+pub struct Sample {
+    pub day: usize,
+    pub input: String,
+    pub phase: String,
+    pub elapsed: Duration,
+}
+
+pub struct Run {
+    pub timestamp_ms: u128,
+    pub commit: String,
+    pub machine: String,
+    pub samples: Vec<Sample>,
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    IoError(io::Error),
+    InvalidLine(usize),
+    ParseIntError(usize, ParseIntError),
+}
+
+impl From<io::Error> for HistoryError {
+    fn from(e: io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "failed to read history: {}", e),
+            Self::InvalidLine(line) => write!(f, "line {}: expected {} fields", line, HEADER.split('\t').count()),
+            Self::ParseIntError(line, e) => write!(f, "line {}: invalid number: {}", line, e),
+        }
+    }
+}
+
+impl Error for HistoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(e) => Some(e),
+            Self::InvalidLine(_) => None,
+            Self::ParseIntError(_, e) => Some(e),
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).output().ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok().map(|s| s.trim().to_string())
+    } else {
+        None
+    }
+}
+
+// Runs with uncommitted changes are marked so they aren't mistaken for the commit itself.
+pub fn current_commit() -> String {
+    match git(&["rev-parse", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if status.is_empty() => hash,
+            _ => format!("{}-dirty", hash),
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+// The system machine id shouldn't be written out as is, so only a hash of it is kept.
+pub fn current_machine() -> String {
+    if let Ok(machine) = env::var(MACHINE_ENV_VAR) {
+        return machine;
+    }
+    ["/etc/machine-id", "/var/lib/dbus/machine-id", "/etc/hostname"]
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok().filter(|id| !id.trim().is_empty()))
+        .map(|id| {
+            let hash = format!("aoc:{}", id.trim()).bytes().fold(0xcbf29ce484222325u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+            format!("{:016x}", hash)
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn samples(results: &[(usize, SolveReport, Duration)]) -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for (day, report, _) in results.iter() {
+        let mut push = |phase: &str, elapsed: Duration| samples.push(Sample {
+            day: *day,
+            input: input_name(&report.path).to_string(),
+            phase: phase.to_string(),
+            elapsed,
+        });
+        if let Some(parse) = &report.parse {
+            push("parse", parse.elapsed);
+        }
+        if let Some(prepare) = &report.prepare {
+            push("prepare", prepare.elapsed);
+        }
+        for (phase, outcome) in [("part_1", &report.part_1), ("part_2", &report.part_2)].iter() {
+            if let Some(PartOutcome::Solved(answer)) = outcome {
+                push(phase, answer.elapsed);
+            }
+        }
+    }
+    samples
+}
+
+pub fn append(path: &str, run: &Run) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", HEADER)?;
+    }
+    let mut out = String::new();
+    for sample in run.samples.iter() {
+        out.push_str(&format!("{}\t{}\t{}\t{}\t{}\t{}\t{}\n", run.timestamp_ms, run.commit, run.machine, sample.day, sample.input, sample.phase, sample.elapsed.as_nanos()));
+    }
+    file.write_all(out.as_bytes())
+}
+
+pub fn record(path: &str, samples: Vec<Sample>) -> io::Result<Run> {
+    let run = Run {
+        timestamp_ms: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis(),
+        commit: current_commit(),
+        machine: current_machine(),
+        samples,
+    };
+    append(path, &run)?;
+    Ok(run)
+}
+
+// Consecutive lines with the same timestamp, commit and machine belong to the same run.
+pub fn load(path: &str) -> Result<Vec<Run>, HistoryError> {
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut runs: Vec<Run> = Vec::new();
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line == HEADER || line.is_empty() {
+            continue;
+        }
+        let number = i + 1;
+        let fields = line.split('\t').collect::<Vec<_>>();
+        if fields.len() != 7 {
+            return Err(HistoryError::InvalidLine(number));
+        }
+        let parse_int = |s: &str| s.parse::<u128>().map_err(|e| HistoryError::ParseIntError(number, e));
+        let timestamp_ms = parse_int(fields[0])?;
+        let sample = Sample {
+            day: parse_int(fields[3])? as usize,
+            input: fields[4].to_string(),
+            phase: fields[5].to_string(),
+            elapsed: Duration::from_nanos(parse_int(fields[6])? as u64),
+        };
+
+        match runs.last_mut() {
+            Some(run) if run.timestamp_ms == timestamp_ms && run.commit == fields[1] && run.machine == fields[2] => run.samples.push(sample),
+            _ => runs.push(Run {
+                timestamp_ms,
+                commit: fields[1].to_string(),
+                machine: fields[2].to_string(),
+                samples: vec![sample],
+            }),
+        }
+    }
+    Ok(runs)
+}
+
+pub struct Comparison<'a> {
+    pub day: usize,
+    pub input: &'a str,
+    pub phase: &'a str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison<'_> {
+    pub fn change(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(1e-9) - 1.0
+    }
+
+    // A phase of a few microseconds can easily double between two runs of the same code, so it only
+    // counts as slower when it also lost more than `min_difference`.
+    pub fn is_regression(&self, threshold: f64, min_difference: Duration) -> bool {
+        self.change() > threshold && self.current.saturating_sub(self.baseline) > min_difference
+    }
+}
+
+fn median(samples: &mut [Duration]) -> Duration {
+    samples.sort();
+    let n = samples.len();
+    if n.is_multiple_of(2) {
+        (samples[n / 2 - 1] + samples[n / 2]) / 2
+    } else {
+        samples[n / 2]
+    }
+}
+
+// Repeated runs record several samples per phase, each side is compared by its median.
+fn medians(run: &Run) -> Vec<((usize, &str, &str), Duration)> {
+    let mut order = Vec::new();
+    let mut samples = HashMap::<_, Vec<Duration>>::new();
+    for sample in run.samples.iter() {
+        let key = (sample.day, sample.input.as_str(), sample.phase.as_str());
+        samples.entry(key).or_insert_with(|| {
+            order.push(key);
+            Vec::new()
+        }).push(sample.elapsed);
+    }
+    order.into_iter().map(|key| (key, median(samples.get_mut(&key).unwrap()))).collect()
+}
+
+// Only phases present in both runs are compared.
+pub fn compare<'a>(baseline: &'a Run, current: &'a Run) -> Vec<Comparison<'a>> {
+    let baseline = medians(baseline).into_iter().collect::<HashMap<_, _>>();
+    medians(current)
+        .into_iter()
+        .filter_map(|(key, elapsed)| {
+            baseline.get(&key).map(|&baseline| Comparison {
+                day: key.0,
+                input: key.1,
+                phase: key.2,
+                baseline,
+                current: elapsed,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,782 @@
+mod history;
+mod scaffold;
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display},
+    io,
+    num::{NonZeroUsize, ParseIntError},
+    path::Path,
+    process,
+    sync::{atomic::{AtomicUsize, Ordering}, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+use problem::{
+    BorrowProblem,
+    INPUTS_DIR,
+    JsonReporter,
+    MemoryStats,
+    PartOutcome,
+    Parts,
+    PreparedProblem,
+    REAL_INPUT,
+    Reporter,
+    SolveOptions,
+    SolveReport,
+    Streamed,
+    TextReporter,
+    TsvReporter,
+    Verdict,
+    bench::{BenchOptions, BenchReport, bench},
+    fetch::{Client, fetch_cached},
+    submit::{Feedback, record_answer, submissions_path, submit},
+    bench_borrowed,
+    discover_inputs,
+    input_name,
+    parse_timeout,
+    solve,
+    solve_borrowed,
+    solve_stream,
+};
+
+const USAGE: &str = "Usage:
+  aoc run [--format text|json|tsv] [--part 1|2|both] [--timeout <SECONDS>] [--memory] [--jobs <N>]
+          [--history <PATH> | --no-history] [--repeat <N>] <DAY>... | --all
+  aoc bench [--iterations <N>] [--warmup <N>] <DAY>... | --all
+  aoc fetch <DAY>... | --all
+  aoc submit [--part 1|2|both] <DAY>...
+  aoc new-day [--grid] [--root <PATH>] [<DAY>]
+  aoc compare [--history <PATH>] [--baseline <COMMIT>] [--threshold <PERCENT>]
+              [--min-diff <MS>] [<DAY>...]
+
+DAY can be a day number (7), a crate name (day_7), or a range (1..5, 1..=25).
+`run` solves every input in the day's inputs/ directory; `bench` uses inputs/real.txt.
+`--timeout` gives up on a part that runs for longer and reports it as TIMEOUT, parsing and preparing count
+toward the first part. A part that times out keeps running in the background until it finishes or aoc exits,
+which slows down the inputs solved after it, so a run with a timed out part isn't recorded to the history.
+`--memory` reports peak heap bytes and allocations for parse, prepare and each part. It needs the runner
+built with `--features memory`, which installs a counting allocator.
+`--jobs` solves up to N inputs at once, results are still reported in day order.
+`run` appends its timings to the history file (aoc-history.tsv in the workspace by default), tagged with
+the git commit and a machine id ($AOC_MACHINE if set). `--repeat` solves every input N times and records
+all of them, only the first is reported, so it can't be used with `--no-history`. `compare` checks the
+latest run on this machine against the previous one, or the latest run of the baseline commit, using the
+median of each phase's samples. It flags phases that got slower by more than the threshold (10% by default)
+and by more than --min-diff (1 ms by default), so noise in phases of a few microseconds isn't reported.
+`fetch` downloads missing inputs/real.txt files using $AOC_SESSION, $AOC_BASE_URL and $AOC_YEAR.
+`submit` solves inputs/real.txt and sends answers that aren't in its .answers file yet, outputs that aren't
+an answer like day 25's part 2 are never sent. Rejected guesses and cooldowns are kept in
+inputs/real.txt.submissions, right answers are added to the .answers file.
+`new-day` creates a day_N crate with a Problem skeleton (a Grid<Tile> input with --grid) and registers it
+in the workspace and in this runner. DAY defaults to the first day that isn't registered yet.";
+
+struct Day {
+    number: usize,
+    dir: &'static str,
+    run: fn(&str, SolveOptions) -> SolveReport,
+    bench: fn(&str, &BenchOptions) -> Result<BenchReport, String>,
+}
+
+fn run_bench<P: PreparedProblem>(path: &str, options: &BenchOptions) -> Result<BenchReport, String> {
+    bench::<P>(path, options).map_err(|e| e.erase().to_string())
+}
+
+fn run_bench_borrowed<P: BorrowProblem>(path: &str, options: &BenchOptions) -> Result<BenchReport, String> {
+    bench_borrowed::<P>(path, options).map_err(|e| e.erase().to_string())
+}
+
+macro_rules! day {
+    ($number:literal, $krate:ident::$problem:ident) => {
+        Day {
+            number: $number,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate)),
+            run: solve::<$krate::$problem>,
+            bench: run_bench::<$krate::$problem>,
+        }
+    };
+    ($number:literal, stream $krate:ident::$problem:ident) => {
+        Day {
+            number: $number,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate)),
+            run: solve_stream::<$krate::$problem>,
+            bench: run_bench::<Streamed<$krate::$problem>>,
+        }
+    };
+    ($number:literal, borrow $krate:ident::$problem:ident) => {
+        Day {
+            number: $number,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($krate)),
+            run: solve_borrowed::<$krate::$problem>,
+            bench: run_bench_borrowed::<$krate::$problem>,
+        }
+    };
+}
+
+const DAYS: [Day; 25] = [
+    day!(1, stream day_1::Day1),
+    day!(2, stream day_2::Day2),
+    day!(3, day_3::Day3),
+    day!(4, day_4::Day4),
+    day!(5, stream day_5::Day5),
+    day!(6, day_6::Day6),
+    day!(7, day_7::Day7),
+    day!(8, day_8::Day8),
+    day!(9, stream day_9::Day9),
+    day!(10, day_10::Day10),
+    day!(11, day_11::Day11),
+    day!(12, day_12::Day12),
+    day!(13, day_13::Day13),
+    day!(14, day_14::Day14),
+    day!(15, day_15::Day15),
+    day!(16, day_16::Day16),
+    day!(17, day_17::Day17),
+    day!(18, day_18::Day18),
+    day!(19, borrow day_19::Day19),
+    day!(20, day_20::Day20),
+    day!(21, borrow day_21::Day21),
+    day!(22, day_22::Day22),
+    day!(23, day_23::Day23),
+    day!(24, day_24::Day24),
+    day!(25, day_25::Day25),
+];
+
+// Days are checked against the registered ones while parsing arguments.
+fn registered_day(number: usize) -> &'static Day {
+    DAYS.iter().find(|day| day.number == number).unwrap()
+}
+
+#[derive(Debug)]
+enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingDays,
+    MissingValue(String),
+    InvalidValue(String),
+    UnknownOption(String),
+    InvalidDay(String),
+    UnregisteredDay(usize),
+    MemoryNotInstalled,
+    RepeatWithoutHistory,
+    ParseIntError(ParseIntError),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "missing command"),
+            Self::UnknownCommand(command) => write!(f, "unknown command `{}`", command),
+            Self::MissingDays => write!(f, "no days to run"),
+            Self::MissingValue(option) => write!(f, "missing value for `{}`", option),
+            Self::InvalidValue(option) => write!(f, "invalid value for `{}`", option),
+            Self::UnknownOption(option) => write!(f, "unknown option `{}`", option),
+            Self::InvalidDay(day) => write!(f, "invalid day `{}`", day),
+            Self::UnregisteredDay(day) => write!(f, "day {} isn't in the runner yet, create it with `aoc new-day {}`", day, day),
+            Self::MemoryNotInstalled => write!(f, "`--memory` needs the runner built with `--features memory`"),
+            Self::RepeatWithoutHistory => write!(f, "`--repeat` only adds samples to the history, it can't be used with `--no-history`"),
+            Self::ParseIntError(e) => write!(f, "invalid number: {}", e),
+        }
+    }
+}
+
+impl From<ParseIntError> for ArgsError {
+    fn from(e: ParseIntError) -> Self {
+        Self::ParseIntError(e)
+    }
+}
+
+fn parse_day(s: &str) -> Result<usize, ArgsError> {
+    let day = s.strip_prefix("day_").unwrap_or(s).parse()?;
+    if (1..=scaffold::LAST_DAY).contains(&day) {
+        Ok(day)
+    } else {
+        Err(ArgsError::InvalidDay(s.to_string()))
+    }
+}
+
+fn parse_days(s: &str) -> Result<Vec<usize>, ArgsError> {
+    if let Some(split) = s.find("..=") {
+        Ok((parse_day(&s[..split])?..=parse_day(&s[split + 3..])?).collect())
+    } else if let Some(split) = s.find("..") {
+        let end = s[split + 2..].parse::<usize>()?;
+        if end > scaffold::LAST_DAY + 1 {
+            return Err(ArgsError::InvalidDay(s.to_string()));
+        }
+        Ok((parse_day(&s[..split])?..end).collect())
+    } else {
+        Ok(vec![parse_day(s)?])
+    }
+}
+
+enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+struct RunOptions {
+    format: Format,
+    solve: SolveOptions,
+    jobs: NonZeroUsize,
+    history: Option<String>,
+    repeat: NonZeroUsize,
+}
+
+struct CompareOptions {
+    history: String,
+    baseline: Option<String>,
+    threshold: f64,
+    min_difference: Duration,
+}
+
+enum Command {
+    Run(Vec<usize>, RunOptions),
+    Bench(Vec<usize>, BenchOptions),
+    Compare(Vec<usize>, CompareOptions),
+    Fetch(Vec<usize>),
+    Submit(Vec<usize>, Parts),
+    NewDay(scaffold::NewDay),
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let command = args.next().ok_or(ArgsError::MissingCommand)?;
+    if !["run", "bench", "compare", "fetch", "submit", "new-day"].contains(&command.as_str()) {
+        return Err(ArgsError::UnknownCommand(command));
+    }
+
+    let mut days = Vec::new();
+    let mut options = BenchOptions::default();
+    let mut run_options = RunOptions {
+        format: Format::Text,
+        solve: SolveOptions::default(),
+        jobs: NonZeroUsize::new(1).unwrap(),
+        history: Some(history::HISTORY_PATH.to_string()),
+        repeat: NonZeroUsize::new(1).unwrap(),
+    };
+    let mut compare_options = CompareOptions {
+        history: history::HISTORY_PATH.to_string(),
+        baseline: None,
+        threshold: 0.1,
+        min_difference: Duration::from_millis(1),
+    };
+    let mut new_day = scaffold::NewDay {
+        root: scaffold::ROOT.into(),
+        day: None,
+        grid: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days.extend(DAYS.iter().map(|day| day.number)),
+            "--format" if command == "run" => {
+                run_options.format = match args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "tsv" => Format::Tsv,
+                    _ => return Err(ArgsError::InvalidValue(arg)),
+                };
+            },
+            "--part" if command == "run" || command == "submit" => {
+                run_options.solve.parts = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse().map_err(|_| ArgsError::InvalidValue(arg))?;
+            },
+            "--memory" if command == "run" => {
+                if !problem::memory::is_installed() {
+                    return Err(ArgsError::MemoryNotInstalled);
+                }
+                run_options.solve.memory = true;
+            },
+            "--timeout" if command == "run" => {
+                run_options.solve.timeout = Some(parse_timeout(&args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?).ok_or(ArgsError::InvalidValue(arg))?);
+            },
+            "--jobs" if command == "run" => {
+                run_options.jobs = NonZeroUsize::new(args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse()?).ok_or(ArgsError::InvalidValue(arg))?;
+            },
+            "--history" if command != "bench" => {
+                let path = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                run_options.history = Some(path.clone());
+                compare_options.history = path;
+            },
+            "--no-history" if command == "run" => run_options.history = None,
+            "--repeat" if command == "run" => {
+                run_options.repeat = NonZeroUsize::new(args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse()?).ok_or(ArgsError::InvalidValue(arg))?;
+            },
+            "--baseline" if command == "compare" => {
+                compare_options.baseline = Some(args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?);
+            },
+            "--threshold" if command == "compare" => {
+                let percent = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse::<f64>().map_err(|_| ArgsError::InvalidValue(arg.clone()))?;
+                if !percent.is_finite() || percent < 0.0 {
+                    return Err(ArgsError::InvalidValue(arg));
+                }
+                compare_options.threshold = percent / 100.0;
+            },
+            "--min-diff" if command == "compare" => {
+                let ms = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse::<f64>().map_err(|_| ArgsError::InvalidValue(arg.clone()))?;
+                if !ms.is_finite() || ms < 0.0 {
+                    return Err(ArgsError::InvalidValue(arg));
+                }
+                compare_options.min_difference = Duration::from_secs_f64(ms / 1000.0);
+            },
+            "--iterations" | "--warmup" if command == "bench" => {
+                let value = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.parse()?;
+                if arg == "--iterations" {
+                    if value == 0 {
+                        return Err(ArgsError::InvalidValue(arg));
+                    }
+                    options.iterations = value;
+                } else {
+                    options.warmup = value;
+                }
+            },
+            "--grid" if command == "new-day" => new_day.grid = true,
+            "--root" if command == "new-day" => new_day.root = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?.into(),
+            option if option.starts_with("--") => return Err(ArgsError::UnknownOption(arg)),
+            _ if command == "new-day" && new_day.day.is_none() => new_day.day = Some(arg.parse()?),
+            _ => days.append(&mut parse_days(&arg)?),
+        }
+    }
+
+    if run_options.repeat.get() > 1 && run_options.history.is_none() {
+        return Err(ArgsError::RepeatWithoutHistory);
+    }
+
+    if let Some(&day) = days.iter().find(|&&number| !DAYS.iter().any(|day| day.number == number)) {
+        return Err(ArgsError::UnregisteredDay(day));
+    }
+
+    if command == "compare" {
+        Ok(Command::Compare(days, compare_options))
+    } else if command == "new-day" {
+        Ok(Command::NewDay(new_day))
+    } else if days.is_empty() {
+        Err(ArgsError::MissingDays)
+    } else if command == "run" {
+        Ok(Command::Run(days, run_options))
+    } else if command == "fetch" {
+        Ok(Command::Fetch(days))
+    } else if command == "submit" {
+        Ok(Command::Submit(days, run_options.solve.parts))
+    } else {
+        Ok(Command::Bench(days, options))
+    }
+}
+
+struct Row<'a> {
+    day: usize,
+    input: &'a str,
+    part: &'a str,
+    elapsed: String,
+    verdict: String,
+    answer: String,
+    memory: Option<MemoryStats>,
+}
+
+impl<'a> Row<'a> {
+    fn new(day: usize, input: &'a str, part: &'a str, elapsed: String, verdict: &str, answer: String) -> Self {
+        Self {
+            day,
+            input,
+            part,
+            elapsed,
+            verdict: verdict.to_string(),
+            answer,
+            memory: None,
+        }
+    }
+
+    fn with_memory(self, memory: Option<MemoryStats>) -> Self {
+        Self {
+            memory,
+            ..self
+        }
+    }
+}
+
+fn print_summary(results: &[(usize, SolveReport, Duration)], total: Duration) {
+    let mut rows = Vec::new();
+    for (day, report, wall) in results.iter() {
+        let input = input_name(&report.path);
+        if let Some(parse) = &report.parse {
+            rows.push(Row::new(*day, input, "parse", format!("{:.6}", parse.elapsed.as_secs_f64()), "-", format!("{} bytes, {} lines", parse.bytes, parse.lines)).with_memory(parse.memory));
+        }
+        if let Some(prepare) = &report.prepare {
+            rows.push(Row::new(*day, input, "prepare", format!("{:.6}", prepare.elapsed.as_secs_f64()), "-", String::new()).with_memory(prepare.memory));
+        }
+        for (part, outcome) in [("part_1", &report.part_1), ("part_2", &report.part_2)].iter() {
+            match outcome {
+                Some(PartOutcome::Solved(answer)) => rows.push(Row::new(*day, input, part, format!("{:.6}", answer.elapsed.as_secs_f64()), &answer.verdict.to_string(), answer.value.clone()).with_memory(answer.memory)),
+                Some(PartOutcome::Skipped) => rows.push(Row::new(*day, input, part, "-".to_string(), "SKIPPED", String::new())),
+                Some(PartOutcome::TimedOut(timeout)) => rows.push(Row::new(*day, input, part, format!(">{:.6}", timeout.as_secs_f64()), "TIMEOUT", String::new())),
+                None => (),
+            }
+        }
+        if let Some(error) = &report.error {
+            rows.push(Row::new(*day, input, error.phase(), "-".to_string(), "ERROR", error.to_string()));
+        }
+        rows.push(Row::new(*day, input, "wall", format!("{:.6}", wall.as_secs_f64()), "-", String::new()));
+    }
+
+    let input_width = rows.iter().map(|row| row.input.len()).max().unwrap_or(0).max("Input".len());
+    let elapsed_width = rows.iter().map(|row| row.elapsed.len()).max().unwrap_or(0).max("Elapsed (s)".len());
+    let show_memory = rows.iter().any(|row| row.memory.is_some());
+    let memory = |peak_bytes: &dyn Display, allocations: &dyn Display| if show_memory { format!("{:>12}  {:>11}  ", peak_bytes, allocations) } else { String::new() };
+
+    println!();
+    println!("{:<4} {:<input_width$} {:<7} {:>elapsed_width$}  {}{:<7}  Answer", "Day", "Input", "Part", "Elapsed (s)", memory(&"Peak (bytes)", &"Allocations"), "Verdict", input_width = input_width, elapsed_width = elapsed_width);
+    for row in rows.iter() {
+        let memory = match row.memory {
+            Some(stats) => memory(&stats.peak_bytes, &stats.allocations),
+            None => memory(&"-", &"-"),
+        };
+        println!("{:<4} {:<input_width$} {:<7} {:>elapsed_width$}  {}{:<7}  {}", row.day, row.input, row.part, row.elapsed, memory, row.verdict, row.answer, input_width = input_width, elapsed_width = elapsed_width);
+    }
+    println!();
+    println!("Total wall time: {:.6}s", total.as_secs_f64());
+}
+
+struct Job {
+    day: &'static Day,
+    path: String,
+}
+
+// Matches the main thread's stack, some days recurse deeply.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+// Jobs are taken in order by up to `threads` workers, `each` is called on the calling thread in job order
+// as soon as every earlier job has finished. The wall time of a job covers only its own solve.
+fn run_jobs(jobs: &[Job], options: SolveOptions, threads: NonZeroUsize, mut each: impl FnMut(&Job, SolveReport, Duration)) {
+    let solve = |job: &Job| {
+        let start = Instant::now();
+        let report = (job.day.run)(&job.path, options);
+        (report, start.elapsed())
+    };
+
+    if threads.get() == 1 {
+        for job in jobs {
+            let (report, wall) = solve(job);
+            each(job, report, wall);
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.get().min(jobs.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            let solve = &solve;
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let job = match jobs.get(index) {
+                        Some(job) => job,
+                        None => break,
+                    };
+                    if sender.send((index, solve(job))).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to spawn worker thread");
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut reported = 0;
+        for (index, result) in receiver {
+            finished.insert(index, result);
+            while let Some((report, wall)) = finished.remove(&reported) {
+                each(&jobs[reported], report, wall);
+                reported += 1;
+            }
+        }
+    });
+}
+
+fn run_days(days: Vec<usize>, options: RunOptions) {
+    let format = options.format;
+    let mut reporter: Box<dyn Reporter> = match format {
+        Format::Text => Box::new(TextReporter::new(io::stdout())),
+        Format::Json => Box::new(JsonReporter::new(io::stdout())),
+        Format::Tsv => Box::new(TsvReporter::new(io::stdout())),
+    };
+
+    let mut failed = false;
+    let mut jobs = Vec::new();
+    for number in days {
+        let day = registered_day(number);
+        match discover_inputs(day.dir) {
+            Ok(paths) => jobs.extend(paths.into_iter().map(|path| Job { day, path })),
+            Err(e) => {
+                eprintln!("Day {}: failed to read {}: {}", day.number, INPUTS_DIR, e);
+                failed = true;
+            },
+        }
+    }
+
+    let start = Instant::now();
+    let mut results = Vec::new();
+    run_jobs(&jobs, options.solve, options.jobs, |job, report, wall| {
+        if let Format::Text = format {
+            println!("Day {} ({}):", job.day.number, input_name(&job.path));
+        }
+        reporter.report(&report).unwrap();
+        results.push((job.day.number, report, wall));
+    });
+    let total = start.elapsed();
+
+    if let Format::Text = format {
+        print_summary(&results, total);
+    }
+
+    if let Some(path) = &options.history {
+        // A timed out part keeps a core busy until it finishes, so every timing taken after it is suspect.
+        let timed_out = |runs: &[(usize, SolveReport, Duration)]| runs.iter().any(|(_, report, _)| report.is_timed_out());
+        let mut repeats = Vec::new();
+        for _ in 1..options.repeat.get() {
+            if timed_out(&results) || timed_out(&repeats) {
+                break;
+            }
+            run_jobs(&jobs, options.solve, options.jobs, |job, report, wall| repeats.push((job.day.number, report, wall)));
+        }
+        if timed_out(&results) || timed_out(&repeats) {
+            eprintln!("Not recording timings to {}, a part timed out", path);
+        } else {
+            let mut samples = history::samples(&results);
+            samples.extend(history::samples(&repeats));
+            if !samples.is_empty() {
+                match history::record(path, samples) {
+                    Ok(run) => eprintln!("Recorded {} timings for {} to {}", run.samples.len(), run.commit, path),
+                    Err(e) => eprintln!("Failed to record timings to {}: {}", path, e),
+                }
+            }
+        }
+    }
+
+    if failed || results.iter().any(|(_, report, _)| !report.is_success()) {
+        process::exit(1);
+    }
+}
+
+fn bench_days(days: Vec<usize>, options: BenchOptions) {
+    let mut failed = false;
+    let mut reports = Vec::new();
+    for number in days {
+        let day = registered_day(number);
+        eprintln!("Benchmarking day {}...", day.number);
+        let path = Path::new(day.dir).join(INPUTS_DIR).join(REAL_INPUT);
+        match (day.bench)(&path.to_string_lossy(), &options) {
+            Ok(report) => reports.push(format!("{{\"day\":{},\"report\":{}}}", day.number, report.to_json())),
+            Err(e) => {
+                eprintln!("Day {} failed: {}", day.number, e);
+                failed = true;
+            },
+        }
+    }
+
+    println!("[\n  {}\n]", reports.join(",\n  "));
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn compare_days(days: Vec<usize>, options: CompareOptions) {
+    let runs = match history::load(&options.history) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("Error: {}: {}", options.history, e);
+            process::exit(1);
+        },
+    };
+
+    // Timings from different machines aren't comparable.
+    let machine = history::current_machine();
+    let runs = runs.iter().filter(|run| run.machine == machine).collect::<Vec<_>>();
+    let (current, earlier) = match runs.split_last() {
+        Some(split) => split,
+        None => {
+            eprintln!("No runs recorded on this machine in {}", options.history);
+            process::exit(1);
+        },
+    };
+    let baseline = match &options.baseline {
+        Some(commit) => earlier.iter().rev().find(|run| run.commit.starts_with(commit.as_str())),
+        None => earlier.last(),
+    };
+    let baseline = match baseline {
+        Some(baseline) => baseline,
+        None => {
+            match &options.baseline {
+                Some(commit) => eprintln!("No earlier run of commit {} on this machine", commit),
+                None => eprintln!("Only one run recorded on this machine, nothing to compare against"),
+            }
+            process::exit(1);
+        },
+    };
+
+    let comparisons = history::compare(baseline, current)
+        .into_iter()
+        .filter(|comparison| days.is_empty() || days.contains(&comparison.day))
+        .collect::<Vec<_>>();
+
+    println!("Baseline: {} ({} ms)", baseline.commit, baseline.timestamp_ms);
+    println!("Current:  {} ({} ms)", current.commit, current.timestamp_ms);
+    println!();
+
+    let input_width = comparisons.iter().map(|c| c.input.len()).max().unwrap_or(0).max("Input".len());
+    println!("{:<4} {:<input_width$} {:<7} {:>12} {:>12} {:>9}", "Day", "Input", "Phase", "Baseline (s)", "Current (s)", "Change", input_width = input_width);
+    let mut regressions = 0;
+    for comparison in comparisons.iter() {
+        let regressed = comparison.is_regression(options.threshold, options.min_difference);
+        if regressed {
+            regressions += 1;
+        }
+        println!(
+            "{:<4} {:<input_width$} {:<7} {:>12.6} {:>12.6} {:>+8.1}%{}",
+            comparison.day,
+            comparison.input,
+            comparison.phase,
+            comparison.baseline.as_secs_f64(),
+            comparison.current.as_secs_f64(),
+            comparison.change() * 100.0,
+            if regressed { "  SLOWER" } else { "" },
+            input_width = input_width,
+        );
+    }
+
+    println!();
+    println!(
+        "{} of {} phases got more than {}% and {} ms slower",
+        regressions,
+        comparisons.len(),
+        options.threshold * 100.0,
+        options.min_difference.as_secs_f64() * 1000.0,
+    );
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
+
+fn fetch_days(days: Vec<usize>) {
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        },
+    };
+
+    let mut failed = false;
+    for number in days {
+        let day = registered_day(number);
+        match fetch_cached(&client, day.number, day.dir) {
+            Ok(input) if input.downloaded => println!("Day {}: downloaded {} ({:016x})", day.number, input.path.display(), input.checksum),
+            Ok(input) => println!("Day {}: already cached at {} ({:016x})", day.number, input.path.display(), input.checksum),
+            Err(e) => {
+                eprintln!("Day {}: {}", day.number, e);
+                failed = true;
+            },
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn submit_days(days: Vec<usize>, parts: Parts) {
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        },
+    };
+
+    let mut failed = false;
+    'days: for number in days {
+        let day = registered_day(number);
+        let path = Path::new(day.dir).join(INPUTS_DIR).join(REAL_INPUT);
+        let options = SolveOptions {
+            parts,
+            ..SolveOptions::default()
+        };
+        let report = (day.run)(&path.to_string_lossy(), options);
+        if let Some(error) = &report.error {
+            eprintln!("Day {}: {}", day.number, error);
+            failed = true;
+            continue;
+        }
+
+        for (part, outcome) in [(1, &report.part_1), (2, &report.part_2)].iter() {
+            let answer = match outcome.as_ref().and_then(PartOutcome::answer) {
+                Some(answer) => answer,
+                None => continue,
+            };
+            match &answer.verdict {
+                Verdict::Pass => println!("Day {} part {}: {} is already confirmed", day.number, part, answer.value),
+                Verdict::Fail { expected } => {
+                    println!("Day {} part {}: {} doesn't match the confirmed answer {}, not submitting", day.number, part, answer.value, expected);
+                    failed = true;
+                },
+                Verdict::Unknown => match answer.submission.as_deref().map(|value| submit(&client, day.number, *part, value, &submissions_path(&path))) {
+                    None => println!("Day {} part {}: {} isn't an answer, not submitting", day.number, part, answer.value),
+                    Some(Ok(submission)) => {
+                        let sent = if submission.sent { "" } else { " (not sent)" };
+                        println!("Day {} part {}: {} is {}{}", day.number, part, answer.value, submission.feedback, sent);
+                        match submission.feedback {
+                            Feedback::Right => {
+                                if let Err(e) = record_answer(&path, *part, &answer.value) {
+                                    eprintln!("Day {}: failed to record answer: {}", day.number, e);
+                                    failed = true;
+                                }
+                            },
+                            Feedback::Wait(_) => {
+                                failed = true;
+                                break 'days;
+                            },
+                            Feedback::AlreadySolved => (),
+                            _ => failed = true,
+                        }
+                    },
+                    Some(Err(e)) => {
+                        eprintln!("Day {} part {}: {}", day.number, part, e);
+                        failed = true;
+                    },
+                },
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(days, options)) => run_days(days, options),
+        Ok(Command::Bench(days, options)) => bench_days(days, options),
+        Ok(Command::Compare(days, options)) => compare_days(days, options),
+        Ok(Command::Fetch(days)) => fetch_days(days),
+        Ok(Command::Submit(days, parts)) => submit_days(days, parts),
+        Ok(Command::NewDay(options)) => match scaffold::new_day(&options) {
+            Ok(written) => {
+                for path in written {
+                    println!("Wrote {}", path.display());
+                }
+                println!("Rebuild the runner to include the new day.");
+            },
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            },
+        },
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        },
+    }
+}
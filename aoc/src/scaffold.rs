@@ -0,0 +1,311 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+pub const LAST_DAY: usize = 25;
+
+const DAYS_ANCHOR: &str = "const DAYS: [Day; ";
+
+const MANIFEST: &str = r#"[package]
+name = "day___DAY__"
+version = "0.1.0"
+authors = ["David Koloski <djkoloski@gmail.com>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+__DEPENDENCIES__"#;
+
+const MAIN: &str = r#"use day___DAY__::Day__DAY__;
+use problem::run;
+
+fn main() {
+    run::<Day__DAY__>(env!("CARGO_MANIFEST_DIR"));
+}
+"#;
+
+const LIB: &str = r#"use std::{error, fmt, num::ParseIntError, str::FromStr};
+use problem::{LineError, Problem};
+
+pub struct Entry {
+    pub value: i64,
+}
+
+#[derive(Debug)]
+pub enum ParseEntryError {
+    ParseIntError(ParseIntError),
+}
+
+impl fmt::Display for ParseEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseIntError(e) => write!(f, "invalid value: {}", e),
+        }
+    }
+}
+
+impl error::Error for ParseEntryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::ParseIntError(e) => Some(e),
+        }
+    }
+}
+
+impl LineError for ParseEntryError {}
+
+impl From<ParseIntError> for ParseEntryError {
+    fn from(e: ParseIntError) -> Self {
+        ParseEntryError::ParseIntError(e)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = ParseEntryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Entry {
+            value: s.parse()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Unsolved,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+pub struct Day__DAY__;
+impl Problem for Day__DAY__ {
+    type Input = Vec<Entry>;
+    type Part1Output = i64;
+    type Part2Output = i64;
+    type Error = Error;
+
+    fn part_1(_input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Err(Error::Unsolved)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Err(Error::Unsolved)
+    }
+}
+"#;
+
+const GRID_LIB: &str = r#"use std::{error, fmt};
+use grid::Grid;
+use problem::{FromChar, InvalidCharError, Problem};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+impl FromChar for Tile {
+    type Err = InvalidCharError;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            c => Err(InvalidCharError(c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Unsolved,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+pub struct Day__DAY__;
+impl Problem for Day__DAY__ {
+    type Input = Grid<Tile>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = Error;
+
+    fn part_1(_input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Err(Error::Unsolved)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Err(Error::Unsolved)
+    }
+}
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    IoError(io::Error),
+    AlreadyExists(PathBuf),
+    InvalidDay(usize),
+    AlreadyRegistered(usize),
+    AllRegistered,
+    MissingAnchor {
+        path: PathBuf,
+        anchor: &'static str,
+    },
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        Self::IoError(e)
+    }
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "{}", e),
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::InvalidDay(day) => write!(f, "day {} isn't between 1 and {}", day, LAST_DAY),
+            Self::AlreadyRegistered(day) => write!(f, "day {} is already registered in the runner", day),
+            Self::AllRegistered => write!(f, "all {} days are already registered", LAST_DAY),
+            Self::MissingAnchor { path, anchor } => write!(f, "couldn't find `{}` in {}", anchor.trim(), path.display()),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+pub struct NewDay {
+    pub root: PathBuf,
+    pub day: Option<usize>,
+    pub grid: bool,
+}
+
+fn runner_path(root: &Path) -> PathBuf {
+    root.join("aoc").join("src").join("main.rs")
+}
+
+fn missing(path: &Path, anchor: &'static str) -> ScaffoldError {
+    ScaffoldError::MissingAnchor {
+        path: path.to_path_buf(),
+        anchor,
+    }
+}
+
+pub fn registered_days(root: &Path) -> Result<Vec<usize>, ScaffoldError> {
+    let path = runner_path(root);
+    let source = fs::read_to_string(&path)?;
+    let start = source.find(DAYS_ANCHOR).ok_or_else(|| missing(&path, DAYS_ANCHOR))?;
+    let end = source[start..].find("\n];").map_or(source.len(), |end| start + end);
+    Ok(source[start..end].lines().filter_map(|line| day_number(line, "day!(")).collect())
+}
+
+// The day number right after `prefix` at the start of `line`, like 7 in `day!(7, day_7::Day7),`.
+fn day_number(line: &str, prefix: &str) -> Option<usize> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    rest[..rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len())].parse().ok()
+}
+
+// Inserts `line` between `start` and `end` so the lines starting with `prefix` stay ordered by day.
+fn insert_line(contents: &str, path: &Path, start: &'static str, end: &str, prefix: &str, day: usize, line: &str) -> Result<String, ScaffoldError> {
+    let block_start = contents.find(start).ok_or_else(|| missing(path, start))? + start.len();
+    let block_end = contents[block_start..].find(end).map_or(contents.len(), |end| block_start + end);
+    let lines = contents[block_start..block_end]
+        .match_indices('\n')
+        .map(|(i, _)| block_start + i + 1)
+        .filter_map(|i| day_number(&contents[i..], prefix).map(|number| (i, number)))
+        .collect::<Vec<_>>();
+    let at = match lines.iter().rfind(|&&(_, number)| number < day) {
+        Some(&(i, _)) => contents[i..].find('\n').map_or(block_end, |end| i + end + 1),
+        None => lines.first().map_or(block_end, |&(i, _)| i),
+    };
+    Ok(format!("{}{}\n{}", &contents[..at], line, &contents[at..]))
+}
+
+// Everything is checked and prepared before the first file is written.
+pub fn new_day(options: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let root = &options.root;
+    let registered = registered_days(root)?;
+    let day = match options.day {
+        Some(day) => day,
+        None => (1..=LAST_DAY).find(|day| !registered.contains(day)).ok_or(ScaffoldError::AllRegistered)?,
+    };
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+    if registered.contains(&day) {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+    let name = format!("day_{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir));
+    }
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = insert_line(&fs::read_to_string(&workspace_path)?, &workspace_path, "members = [", "]", "\"day_", day, &format!("    \"{}\",", name))?;
+
+    let aoc_manifest_path = root.join("aoc").join("Cargo.toml");
+    let aoc_manifest = insert_line(&fs::read_to_string(&aoc_manifest_path)?, &aoc_manifest_path, "[dependencies]", "\n[", "day_", day, &format!("{} = {{ path = \"../{}\" }}", name, name))?;
+
+    let runner_path = runner_path(root);
+    let runner = fs::read_to_string(&runner_path)?.replacen(&format!("{}{}]", DAYS_ANCHOR, registered.len()), &format!("{}{}]", DAYS_ANCHOR, registered.len() + 1), 1);
+    let runner = insert_line(&runner, &runner_path, DAYS_ANCHOR, "\n];", "day!(", day, &format!("    day!({}, {}::Day{}),", day, name, day))?;
+
+    let dependencies = if options.grid {
+        "grid = { workspace = true }\nproblem = { workspace = true }\n"
+    } else {
+        "problem = { workspace = true }\n"
+    };
+    let lib = if options.grid { GRID_LIB } else { LIB };
+    let fill = |template: &str| template.replace("__DEPENDENCIES__", dependencies).replace("__DAY__", &day.to_string());
+
+    // inputs/ starts out empty, .gitkeep keeps it in the repository until the first input is added.
+    let files = [
+        (dir.join("Cargo.toml"), fill(MANIFEST)),
+        (dir.join("src").join("lib.rs"), fill(lib)),
+        (dir.join("src").join("main.rs"), fill(MAIN)),
+        (dir.join(problem::INPUTS_DIR).join(".gitkeep"), String::new()),
+    ];
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join(problem::INPUTS_DIR))?;
+    for (path, contents) in files.iter() {
+        fs::write(path, contents)?;
+    }
+    fs::write(&workspace_path, workspace)?;
+    fs::write(&aoc_manifest_path, aoc_manifest)?;
+    fs::write(&runner_path, runner)?;
+
+    let mut written = files.iter().map(|(path, _)| path.clone()).collect::<Vec<_>>();
+    written.extend([workspace_path, aoc_manifest_path, runner_path]);
+    Ok(written)
+}
@@ -0,0 +1,116 @@
+use std::{
+    env,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Output},
+};
+
+const HEADER: &str = "timestamp_ms\tcommit\tmachine\tday\tinput\tphase\telapsed_ns";
+
+// Each line is a (timestamp, commit, machine, phase, nanoseconds) sample of day 1's real input.
+fn history(name: &str, samples: &[(u64, &str, &str, &str, u64)]) -> PathBuf {
+    let path = env::temp_dir().join(format!("aoc-history-{}-{}.tsv", name, process::id()));
+    let mut contents = format!("{}\n", HEADER);
+    for (timestamp, commit, machine, phase, elapsed) in samples.iter() {
+        contents.push_str(&format!("{}\t{}\t{}\t1\treal\t{}\t{}\n", timestamp, commit, machine, phase, elapsed));
+    }
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).env("AOC_MACHINE", "test").output().unwrap()
+}
+
+fn compare(path: &Path, args: &[&str]) -> (bool, String) {
+    let output = aoc(&[&["compare", "--history", path.to_str().unwrap()], args].concat());
+    (output.status.success(), String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn phase_line<'a>(stdout: &'a str, phase: &str) -> &'a str {
+    stdout.lines().find(|line| line.starts_with("1 ") && line.contains(phase)).unwrap_or_else(|| panic!("no {} in\n{}", phase, stdout))
+}
+
+#[test]
+fn compares_medians_of_repeated_samples() {
+    // The baseline has an even number of samples, the current run an odd number.
+    let path = history("medians", &[
+        (1, "aaa", "test", "part_1", 1_000_000),
+        (1, "aaa", "test", "part_1", 3_000_000),
+        (2, "bbb", "test", "part_1", 5_000_000),
+        (2, "bbb", "test", "part_1", 1_000_000),
+        (2, "bbb", "test", "part_1", 9_000_000),
+    ]);
+    let (success, stdout) = compare(&path, &[]);
+    let line = phase_line(&stdout, "part_1");
+    assert!(line.contains("0.002000") && line.contains("0.005000") && line.contains("+150.0%"), "{}", line);
+    assert!(line.ends_with("SLOWER"), "{}", line);
+    assert!(!success);
+    assert!(stdout.contains("1 of 1 phases"));
+}
+
+#[test]
+fn small_differences_are_not_regressions() {
+    let path = history("min_difference", &[
+        (1, "aaa", "test", "part_1", 10_000),
+        (2, "bbb", "test", "part_1", 30_000),
+    ]);
+    let (success, stdout) = compare(&path, &[]);
+    assert!(success);
+    assert!(!phase_line(&stdout, "part_1").contains("SLOWER"));
+
+    let (success, stdout) = compare(&path, &["--min-diff", "0.01"]);
+    assert!(!success);
+    assert!(phase_line(&stdout, "part_1").ends_with("SLOWER"));
+}
+
+#[test]
+fn changes_within_the_threshold_are_not_regressions() {
+    let path = history("threshold", &[
+        (1, "aaa", "test", "part_1", 10_000_000),
+        (2, "bbb", "test", "part_1", 15_000_000),
+    ]);
+    assert!(!compare(&path, &[]).0);
+    let (success, stdout) = compare(&path, &["--threshold", "60"]);
+    assert!(success);
+    assert!(!phase_line(&stdout, "part_1").contains("SLOWER"));
+}
+
+#[test]
+fn groups_consecutive_samples_into_runs() {
+    // Same timestamp but another commit starts a new run, and runs from other machines are ignored.
+    let path = history("grouping", &[
+        (1, "aaa", "test", "parse", 1_000_000),
+        (1, "aaa", "test", "part_1", 2_000_000),
+        (1, "bbb", "test", "parse", 1_000_000),
+        (1, "bbb", "test", "part_1", 2_000_000),
+        (1, "bbb", "test", "part_2", 3_000_000),
+        (3, "ccc", "other", "parse", 1_000_000),
+    ]);
+    let (success, stdout) = compare(&path, &[]);
+    assert!(success, "{}", stdout);
+    assert!(stdout.contains("Baseline: aaa") && stdout.contains("Current:  bbb"), "{}", stdout);
+    // part_2 is only in the current run, so it isn't compared.
+    assert!(stdout.contains("0 of 2 phases"), "{}", stdout);
+}
+
+#[test]
+fn baseline_picks_a_commit() {
+    let path = history("baseline", &[
+        (1, "aaa", "test", "part_1", 1_000_000),
+        (2, "bbb", "test", "part_1", 9_000_000),
+        (3, "ccc", "test", "part_1", 9_000_000),
+    ]);
+    assert!(compare(&path, &[]).0);
+    let (success, stdout) = compare(&path, &["--baseline", "aa"]);
+    assert!(!success);
+    assert!(stdout.contains("Baseline: aaa"), "{}", stdout);
+}
+
+#[test]
+fn rejects_repeat_without_history() {
+    let output = aoc(&["run", "--repeat", "3", "--no-history", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--no-history"));
+    assert!(output.stdout.is_empty());
+}
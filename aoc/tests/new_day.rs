@@ -0,0 +1,105 @@
+use std::{
+    env,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Output},
+};
+
+const REWRITTEN: [&str; 3] = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/main.rs"];
+
+// A copy of the workspace's manifests and runner with `day` taken out again.
+fn root_without(name: &str, day: usize) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let removed = [format!("\"day_{}\",", day), format!("day_{} = ", day), format!("day!({}, ", day)];
+    for path in REWRITTEN.iter() {
+        let contents = fs::read_to_string(workspace.join(path)).unwrap();
+        let contents = contents
+            .lines()
+            .filter(|line| !removed.iter().any(|removed| line.trim_start().starts_with(removed.as_str())))
+            .map(|line| format!("{}\n", line.replace("[Day; 25]", "[Day; 24]")))
+            .collect::<String>();
+        fs::write(root.join(path), contents).unwrap();
+    }
+    root
+}
+
+fn new_day(root: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).arg("new-day").arg("--root").arg(root).args(args).output().unwrap()
+}
+
+// Builds scaffolded crates in a workspace of their own that points at this one's shared crates. Its target
+// directory is kept between runs so the shared crates are only built once.
+fn check(name: &str, crates: &[PathBuf]) -> Output {
+    let root = env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+    let dependencies = &manifest[manifest.find("[workspace.dependencies]").unwrap()..];
+    let dependencies = dependencies.replace("path = \"", &format!("path = \"{}/", workspace.display()));
+    let mut members = Vec::new();
+    for path in crates.iter() {
+        let name = path.file_name().unwrap().to_str().unwrap();
+        members.push(format!("\"{}\"", name));
+        fs::create_dir_all(root.join(name).join("src")).unwrap();
+        for file in ["Cargo.toml", "src/lib.rs", "src/main.rs"].iter() {
+            fs::copy(path.join(file), root.join(name).join(file)).unwrap();
+        }
+    }
+    fs::write(root.join("Cargo.toml"), format!("[workspace]\nresolver = \"2\"\nmembers = [{}]\n\n{}", members.join(", "), dependencies)).unwrap();
+    fs::copy(workspace.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+
+    Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["check", "--offline", "--workspace", "--all-targets"].iter())
+        .current_dir(&root)
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("new-day-check"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn scaffolds_first_missing_day() {
+    let root = root_without("scaffolds_first_missing_day", 7);
+    let output = new_day(&root, &[]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for path in REWRITTEN.iter() {
+        assert_eq!(fs::read_to_string(root.join(path)).unwrap(), fs::read_to_string(workspace.join(path)).unwrap(), "{}", path);
+    }
+
+    let manifest = fs::read_to_string(root.join("day_7").join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"day_7\""));
+    assert!(manifest.contains("authors = ["));
+    assert!(fs::read_to_string(root.join("day_7").join("src").join("main.rs")).unwrap().contains("run::<Day7>"));
+    assert!(fs::read_to_string(root.join("day_7").join("src").join("lib.rs")).unwrap().contains("pub struct Day7;"));
+    assert!(root.join("day_7").join("inputs").join(".gitkeep").exists());
+}
+
+#[test]
+fn rejects_registered_and_invalid_days() {
+    let root = root_without("rejects_registered_and_invalid_days", 7);
+    for day in ["8", "26", "0"].iter() {
+        let output = new_day(&root, &[day]);
+        assert!(!output.status.success(), "day {}", day);
+    }
+    assert!(!root.join("day_8").exists());
+    assert!(!root.join("day_26").exists());
+    assert!(fs::read_to_string(root.join("aoc/src/main.rs")).unwrap().contains("[Day; 24]"));
+}
+
+#[test]
+fn scaffolded_crates_compile() {
+    let lines = root_without("scaffolded_crates_compile_lines", 7);
+    assert!(new_day(&lines, &["7"]).status.success());
+    let grid = root_without("scaffolded_crates_compile_grid", 8);
+    assert!(new_day(&grid, &["--grid", "8"]).status.success());
+
+    let output = check("scaffolded_crates_compile", &[lines.join("day_7"), grid.join("day_8")]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
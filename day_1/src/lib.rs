@@ -0,0 +1,74 @@
+use std::fmt;
+use problem::{NoSolution, RecordSource, StreamProblem, Submission};
+use smallbitvec::SmallBitVec;
+
+fn solve_2(values: impl Iterator<Item = i32>, target: i32) -> Option<(i32, i32)> {
+    let half = target / 2 + 1;
+    let mut bits = SmallBitVec::from_elem(half as usize, false);
+    for value in values {
+        let index = if value < half { value } else { target - value };
+        if index >= 0 {
+            if bits[index as usize] {
+                return Some((index, target - index))
+            } else {
+                bits.set(index as usize, true);
+            }
+        }
+    }
+    None
+}
+
+pub struct Solution<T>(T);
+
+impl<T: AsRef<[i32]>> Solution<T> {
+    fn product(&self) -> i32 {
+        self.0.as_ref().iter().product()
+    }
+}
+
+impl<T: AsRef<[i32]>> fmt::Display for Solution<T> {
+    fn fmt<'a>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product())?;
+        for (i, &v) in self.0.as_ref().iter().enumerate() {
+            if i == 0 {
+                write!(f, " = {}", v)?;
+            } else {
+                write!(f, " * {}", v)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The puzzle only wants the product, the factors are shown for checking.
+impl<T: AsRef<[i32]>> Submission for Solution<T> {
+    fn submission(&self) -> Option<String> {
+        Some(self.product().to_string())
+    }
+}
+
+const TARGET: i32 = 2020;
+
+pub struct Day1;
+impl StreamProblem for Day1 {
+    type Record = i32;
+    type Part1Output = Solution<[i32; 2]>;
+    type Part2Output = Solution<[i32; 3]>;
+    type Error = NoSolution;
+
+    fn part_1(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part1Output, Self::Error> {
+        let (a, b) = solve_2(records.records(), TARGET).ok_or(NoSolution)?;
+        Ok(Solution([a, b]))
+    }
+
+    fn part_2(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part2Output, Self::Error> {
+        // Each value is paired against the ones after it, which needs the records in memory.
+        let input = records.records().collect::<Vec<_>>();
+        for (i, &v) in input.iter().enumerate() {
+            if let Some((a, b)) = solve_2(input[i + 1..].iter().copied(), TARGET - v) {
+                return Ok(Solution([v, a, b]));
+            }
+        }
+        Err(NoSolution)
+    }
+}
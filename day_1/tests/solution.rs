@@ -0,0 +1,18 @@
+use day_1::Day1;
+use problem::{StreamProblem, Submission};
+
+#[test]
+fn finds_the_triple_in_input_order() {
+    // 10 + 20 + 1990 is also a solution, but the scan finds 1010 first.
+    let records = vec![1010, 1000, 10, 20, 1990];
+    assert_eq!(Day1::part_2(&records).unwrap().to_string(), "10100000 = 1010 * 10 * 1000");
+}
+
+#[test]
+fn submits_only_the_product() {
+    let records = vec![1721, 979, 366, 299, 675, 1456];
+    let part_1 = Day1::part_1(&records).unwrap();
+    assert_eq!(part_1.to_string(), "514579 = 299 * 1721");
+    assert_eq!(part_1.submission().as_deref(), Some("514579"));
+    assert_eq!(Day1::part_2(&records).unwrap().submission().as_deref(), Some("241861950"));
+}
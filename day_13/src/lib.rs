@@ -0,0 +1,55 @@
+use std::num::ParseIntError;
+use problem::{NoSolution, ProblemInput, Problem};
+
+#[derive(ProblemInput)]
+pub struct Schedule {
+    pub departure_time: u64,
+    #[problem_input(separator = ",", with = parse_bus)]
+    pub bus_schedule: Vec<Option<u64>>,
+}
+
+fn parse_bus(s: &str) -> Result<Option<u64>, ParseIntError> {
+    if s == "x" {
+        Ok(None)
+    } else {
+        Ok(Some(s.parse()?))
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+pub struct Day13;
+impl Problem for Day13 {
+    type Input = Schedule;
+    type Part1Output = u64;
+    type Part2Output = u64;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let (time, bus) = input.bus_schedule.iter().filter_map(|b| *b).map(|b| (b - input.departure_time % b, b)).min().unwrap();
+        Ok(time * bus)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        let mut time = 0;
+        let mut step = 1;
+        for (i, b) in input.bus_schedule.iter().enumerate().filter_map(|(i, b)| b.map(|b| (i, b))) {
+            while time % b != (b - i as u64 % b) % b {
+                time += step;
+            }
+            step = lcm(step, b);
+        }
+        Ok(time)
+    }
+}
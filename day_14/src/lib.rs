@@ -0,0 +1,157 @@
+use std::{collections::HashMap, error::Error, fmt, ops::Range, str::FromStr};
+use problem::{LineError, NoSolution, PatternError, Problem, parse_pattern};
+
+pub enum Instruction {
+    SetMask {
+        value: u64,
+        mask: u64,
+    },
+    SetMem {
+        address: u64,
+        value: u64,
+    },
+}
+
+const MASK_PATTERN: &str = "mask = {}";
+
+#[derive(Debug)]
+pub enum ParseInstructionError {
+    PatternError(PatternError),
+    InvalidInstruction,
+    InvalidBit {
+        column: usize,
+        c: char,
+    },
+}
+
+impl fmt::Display for ParseInstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PatternError(e) => write!(f, "{}", e),
+            Self::InvalidInstruction => write!(f, "expected a mask or memory assignment"),
+            Self::InvalidBit { c, .. } => write!(f, "invalid mask bit `{}`", c),
+        }
+    }
+}
+
+impl Error for ParseInstructionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PatternError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl LineError for ParseInstructionError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            Self::PatternError(e) => e.columns(),
+            Self::InvalidInstruction => None,
+            Self::InvalidBit { column, .. } => Some(*column..*column + 1),
+        }
+    }
+}
+
+impl From<PatternError> for ParseInstructionError {
+    fn from(e: PatternError) -> Self {
+        Self::PatternError(e)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("mask") {
+            let (bits,): (String,) = parse_pattern!(MASK_PATTERN, s)?;
+            let start = MASK_PATTERN.len() - 2;
+            let (value, mask) = bits
+                .chars()
+                .rev()
+                .enumerate()
+                .try_fold((0, 0), |(value, mask), (i, bit)| {
+                    let one = 1 << i;
+                    Ok(match bit {
+                        '0' => (value, mask),
+                        '1' => (value | one, mask),
+                        'X' => (value, mask | one),
+                        c => return Err(ParseInstructionError::InvalidBit {
+                            column: start + bits.len() - 1 - i,
+                            c,
+                        }),
+                    })
+                })?;
+            Ok(Instruction::SetMask {
+                value,
+                mask,
+            })
+        } else if s.starts_with("mem") {
+            let (address, value) = parse_pattern!("mem[{}] = {}", s)?;
+            Ok(Instruction::SetMem {
+                address,
+                value,
+            })
+        } else {
+            Err(ParseInstructionError::InvalidInstruction)
+        }
+    }
+}
+
+pub struct Day14;
+impl Problem for Day14 {
+    type Input = Vec<Instruction>;
+    type Part1Output = u64;
+    type Part2Output = u64;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let mut values = HashMap::new();
+        let mut mask_value = 0;
+        let mut mask_mask = 0;
+        for i in input.iter() {
+            match i {
+                Instruction::SetMask { value, mask } => {
+                    mask_value = *value;
+                    mask_mask = *mask;
+                },
+                Instruction::SetMem { address, value } => {
+                    let write_value = mask_value | (value & mask_mask);
+                    values.insert(*address, write_value);
+                },
+            }
+        }
+
+        Ok(values.values().sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        let mut values = HashMap::new();
+
+        let mut mask_value = 0;
+        let mut mask_mask = 0;
+        for instruction in input.iter() {
+            match instruction {
+                Instruction::SetMask { value, mask } => {
+                    mask_value = *value;
+                    mask_mask = *mask;
+                },
+                Instruction::SetMem { address, value } => {
+                    fn set_value(address: u64, mask: u64, value: u64, values: &mut HashMap<u64, u64>) {
+                        let zeros = mask.trailing_zeros();
+                        if zeros == 64 {
+                            values.insert(address, value);
+                        } else {
+                            let bit = 1 << zeros;
+                            let next_mask = mask & !bit;
+                            set_value(address, next_mask, value, values);
+                            set_value(address | bit, next_mask, value, values);
+                        }
+                    }
+                    set_value((address | mask_value) & !mask_mask, mask_mask, *value, &mut values);
+                },
+            }
+        }
+
+        Ok(values.values().sum())
+    }
+}
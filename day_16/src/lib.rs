@@ -0,0 +1,169 @@
+use std::{collections::HashSet, error::Error, fmt, ops::Range, str::FromStr};
+
+use problem::{CSV, LineError, NoSolution, PatternError, ProblemInput, Problem, parse_pattern};
+
+pub struct TicketField {
+    name: String,
+    ranges: Vec<(u32, u32)>,
+}
+
+impl TicketField {
+    fn matches(&self, value: u32) -> bool {
+        self.ranges.iter().any(|&(lower, upper)| value >= lower && value <= upper)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseTicketFieldError {
+    PatternError(PatternError),
+    InvalidRange {
+        offset: usize,
+        error: PatternError,
+    },
+}
+
+impl fmt::Display for ParseTicketFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PatternError(e) => write!(f, "{}", e),
+            Self::InvalidRange { error, .. } => write!(f, "invalid range: {}", error),
+        }
+    }
+}
+
+impl Error for ParseTicketFieldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PatternError(e) => Some(e),
+            Self::InvalidRange { error, .. } => Some(error),
+        }
+    }
+}
+
+impl LineError for ParseTicketFieldError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            Self::PatternError(e) => e.columns(),
+            Self::InvalidRange { offset, error } => error.columns().map(|c| c.start + offset..c.end + offset),
+        }
+    }
+}
+
+impl From<PatternError> for ParseTicketFieldError {
+    fn from(e: PatternError) -> Self {
+        Self::PatternError(e)
+    }
+}
+
+impl FromStr for TicketField {
+    type Err = ParseTicketFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, ranges): (String, String) = parse_pattern!("{}: {}", s)?;
+
+        let mut offset = s.len() - ranges.len();
+        let ranges = ranges.split(" or ")
+            .map(|range| {
+                let result = parse_pattern!("{}-{}", range).map_err(|error| ParseTicketFieldError::InvalidRange {
+                    offset,
+                    error,
+                });
+                offset += range.len() + 4;
+                result
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            name,
+            ranges,
+        })
+    }
+}
+
+pub type Ticket = CSV<u32>;
+
+#[derive(ProblemInput)]
+#[problem_input(sections)]
+pub struct Input {
+    fields: Vec<TicketField>,
+    #[problem_input(title = "your ticket:", line)]
+    your_ticket: Ticket,
+    #[problem_input(title = "nearby tickets:")]
+    nearby_tickets: Vec<Ticket>,
+}
+
+pub struct Day16;
+impl Problem for Day16 {
+    type Input = Input;
+    type Part1Output = u32;
+    type Part2Output = u64;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Ok(
+            input.nearby_tickets.iter()
+                .map(|t| {
+                    t.values.iter()
+                        .filter(|&v| {
+                            !input.fields.iter()
+                                .any(|f| f.matches(*v))
+                        })
+                        .sum::<u32>()
+                })
+                .sum()
+        )
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        let mut candidates = HashSet::new();
+        for i in 0..input.fields.len() {
+            candidates.insert(i);
+        }
+        let mut field_candidates = Vec::new();
+        for _ in 0..input.fields.len() {
+            field_candidates.push(candidates.clone());
+        }
+
+        for ticket in input.nearby_tickets.iter()
+            .filter(|&t| {
+                t.values.iter().all(|&v| {
+                    input.fields.iter().any(|f| f.matches(v))
+                })
+            })
+        {
+            for (i, &v) in ticket.values.iter().enumerate() {
+                field_candidates[i] = field_candidates[i].iter()
+                    .cloned()
+                    .filter(|&c| input.fields[c].matches(v))
+                    .collect();
+            }
+        }
+
+        let mut fields = vec![0; input.fields.len()];
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 0..field_candidates.len() {
+                if field_candidates[i].len() == 1 {
+                    let value = *field_candidates[i].iter().next().unwrap();
+                    fields[i] = value;
+                    field_candidates[i].clear();
+                    for candidates in field_candidates.iter_mut() {
+                        candidates.remove(&value);
+                    }
+                    changed = true;
+                }
+            }
+        }
+
+        let mut total = 1;
+        for (i, v) in input.your_ticket.values.iter().enumerate() {
+            if input.fields[fields[i]].name.starts_with("departure") {
+                total *= *v as u64;
+            }
+        }
+
+        Ok(total)
+    }
+}
@@ -0,0 +1,152 @@
+use grid::Grid;
+use problem::{FromChar, InvalidCharError, NoSolution, Problem};
+
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub enum State {
+    #[default]
+    Inactive,
+    Active,
+}
+
+impl FromChar for State {
+    type Err = InvalidCharError;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '.' => Ok(State::Inactive),
+            '#' => Ok(State::Active),
+            c => Err(InvalidCharError(c)),
+        }
+    }
+}
+
+fn simulate_iters(grid: &Grid<State>, steps: usize) -> usize {
+    let padding = steps as i32 + 1;
+    let width = grid.width() as i32 + padding * 2;
+    let height = grid.height() as i32 + padding * 2;
+    let depth = 1 + padding * 2;
+    let mut space = vec![State::Inactive; (width * height * depth) as usize];
+
+    for (x, y) in grid.enumerate() {
+        let index = x + steps as i32 + width * (y + steps as i32 + height * padding);
+        space[index as usize] = *grid.get(x, y);
+    }
+
+    for _ in 0..steps {
+        let mut next_space = vec![State::Inactive; (width * height * depth) as usize];
+
+        for x in 1..width - 1 {
+            for y in 1..height - 1 {
+                for z in 1..depth - 1 {
+                    let mut neighbors = 0;
+                    for dx in -1..=1 {
+                        for dy in -1..=1 {
+                            for dz in -1..=1 {
+                                if dx != 0 || dy != 0 || dz != 0 {
+                                    let index = x + dx + width * (y + dy + height * (z + dz));
+                                    match space[index as usize] {
+                                        State::Inactive => (),
+                                        State::Active => neighbors += 1,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    let index = x + width * (y + height * z);
+                    match space[index as usize] {
+                        State::Inactive => {
+                            if neighbors == 3 {
+                                next_space[index as usize] = State::Active;
+                            }
+                        },
+                        State::Active => {
+                            if neighbors == 2 || neighbors == 3 {
+                                next_space[index as usize] = State::Active;
+                            }
+                        },
+                    }
+                }
+            }
+        }
+
+        space = next_space;
+    }
+
+    space.iter().filter(|&s| *s == State::Active).count()
+}
+
+fn simulate_iters_4d(grid: &Grid<State>, steps: usize) -> usize {
+    let padding = steps as i32 + 1;
+    let width = grid.width() as i32 + padding * 2;
+    let height = grid.height() as i32 + padding * 2;
+    let depth = 1 + padding * 2;
+    let hyper = 1 + padding * 2;
+    let mut space = vec![State::Inactive; (width * height * depth * hyper) as usize];
+
+    for (x, y) in grid.enumerate() {
+        let index = x + steps as i32 + width * (y + steps as i32 + height * (padding + depth * padding));
+        space[index as usize] = *grid.get(x, y);
+    }
+
+    for _ in 0..steps {
+        let mut next_space = vec![State::Inactive; (width * height * depth * hyper) as usize];
+
+        for x in 1..width - 1 {
+            for y in 1..height - 1 {
+                for z in 1..depth - 1 {
+                    for w in 1..hyper - 1 {
+                        let mut neighbors = 0;
+                        for dx in -1..=1 {
+                            for dy in -1..=1 {
+                                for dz in -1..=1 {
+                                    for dw in -1..=1 {
+                                        if dx != 0 || dy != 0 || dz != 0 || dw != 0 {
+                                            let index = x + dx + width * (y + dy + height * (z + dz + depth * (w + dw)));
+                                            match space[index as usize] {
+                                                State::Inactive => (),
+                                                State::Active => neighbors += 1,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        let index = x + width * (y + height * (z + depth * w));
+                        match space[index as usize] {
+                            State::Inactive => {
+                                if neighbors == 3 {
+                                    next_space[index as usize] = State::Active;
+                                }
+                            },
+                            State::Active => {
+                                if neighbors == 2 || neighbors == 3 {
+                                    next_space[index as usize] = State::Active;
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+
+        space = next_space;
+    }
+
+    space.iter().filter(|&s| *s == State::Active).count()
+}
+
+pub struct Day17;
+impl Problem for Day17 {
+    type Input = Grid<State>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Ok(simulate_iters(input, 6))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Ok(simulate_iters_4d(input, 6))
+    }
+}
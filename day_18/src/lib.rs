@@ -0,0 +1,215 @@
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+use problem::{LineError, Problem};
+
+#[derive(Debug)]
+pub enum Token {
+    Int(u64),
+    Plus,
+    Asterisk,
+    LeftParen,
+    RightParen,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    InvalidChar {
+        column: usize,
+        c: char,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar { c, .. } => write!(f, "invalid character `{}`", c),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl LineError for ParseError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            Self::InvalidChar { column, c } => Some(*column..*column + c.len_utf8()),
+        }
+    }
+}
+
+pub struct Expression {
+    tokens: Vec<Token>,
+}
+
+pub enum Operator {
+    Add,
+    Mul,
+    LeftParen,
+}
+
+#[derive(Debug)]
+pub enum EvaluationError {
+    MissingLHS,
+    MissingRHS,
+    MismatchedParens,
+    InvalidExpression,
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLHS => write!(f, "operator is missing its left operand"),
+            Self::MissingRHS => write!(f, "operator is missing its right operand"),
+            Self::MismatchedParens => write!(f, "mismatched parentheses"),
+            Self::InvalidExpression => write!(f, "invalid expression"),
+        }
+    }
+}
+
+impl Error for EvaluationError {}
+
+impl Expression {
+    fn add(outputs: &mut Vec<u64>) -> Result<u64, EvaluationError> {
+        let lhs = outputs.pop().ok_or(EvaluationError::MissingLHS)?;
+        let rhs = outputs.pop().ok_or(EvaluationError::MissingRHS)?;
+        Ok(lhs + rhs)
+    }
+
+    fn mul(outputs: &mut Vec<u64>) -> Result<u64, EvaluationError> {
+        let lhs = outputs.pop().ok_or(EvaluationError::MissingLHS)?;
+        let rhs = outputs.pop().ok_or(EvaluationError::MissingRHS)?;
+        Ok(lhs * rhs)
+    }
+
+    fn reduce(outputs: &mut Vec<u64>, operators: &mut Vec<Operator>) -> Result<(), EvaluationError> {
+        while !operators.is_empty() {
+            let result = match operators[operators.len() - 1] {
+                Operator::Add => Self::add(outputs)?,
+                Operator::Mul => Self::mul(outputs)?,
+                Operator::LeftParen => break,
+            };
+            outputs.push(result);
+            operators.pop();
+        }
+        Ok(())
+    }
+
+    fn reduce_precedence(outputs: &mut Vec<u64>, operators: &mut Vec<Operator>) -> Result<(), EvaluationError> {
+        while !operators.is_empty() {
+            let result = match operators[operators.len() - 1] {
+                Operator::Add => Self::add(outputs)?,
+                Operator::Mul => break,
+                Operator::LeftParen => break,
+            };
+            outputs.push(result);
+            operators.pop();
+        }
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<u64, EvaluationError> {
+        let mut outputs = Vec::new();
+        let mut operators = Vec::new();
+
+        for t in self.tokens.iter() {
+            match t {
+                Token::Int(i) => outputs.push(*i),
+                Token::Plus => {
+                    Self::reduce(&mut outputs, &mut operators)?;
+                    operators.push(Operator::Add);
+                },
+                Token::Asterisk => {
+                    Self::reduce(&mut outputs, &mut operators)?;
+                    operators.push(Operator::Mul);
+                },
+                Token::LeftParen => operators.push(Operator::LeftParen),
+                Token::RightParen => {
+                    Self::reduce(&mut outputs, &mut operators)?;
+                    operators.pop().ok_or(EvaluationError::MismatchedParens)?;
+                }
+            }
+        }
+
+        Self::reduce(&mut outputs, &mut operators)?;
+
+        if outputs.len() != 1 {
+            Err(EvaluationError::InvalidExpression)
+        } else {
+            Ok(outputs.pop().unwrap())
+        }
+    }
+
+    fn evaluate_precedence(&self) -> Result<u64, EvaluationError> {
+        let mut outputs = Vec::new();
+        let mut operators = Vec::new();
+
+        for t in self.tokens.iter() {
+            match t {
+                Token::Int(i) => outputs.push(*i),
+                Token::Plus => {
+                    Self::reduce_precedence(&mut outputs, &mut operators)?;
+                    operators.push(Operator::Add);
+                },
+                Token::Asterisk => {
+                    Self::reduce(&mut outputs, &mut operators)?;
+                    operators.push(Operator::Mul);
+                },
+                Token::LeftParen => operators.push(Operator::LeftParen),
+                Token::RightParen => {
+                    Self::reduce(&mut outputs, &mut operators)?;
+                    operators.pop().ok_or(EvaluationError::MismatchedParens)?;
+                }
+            }
+        }
+
+        Self::reduce(&mut outputs, &mut operators)?;
+
+        if outputs.len() != 1 {
+            Err(EvaluationError::InvalidExpression)
+        } else {
+            Ok(outputs.pop().unwrap())
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = s.char_indices().filter_map(|(column, c)| match c {
+            ' ' => None,
+            '0'..='9' => Some(Ok(Token::Int(c as u64 - '0' as u64))),
+            '+' => Some(Ok(Token::Plus)),
+            '*' => Some(Ok(Token::Asterisk)),
+            '(' => Some(Ok(Token::LeftParen)),
+            ')' => Some(Ok(Token::RightParen)),
+            c => Some(Err(ParseError::InvalidChar { column, c })),
+        }).collect::<Result<_, _>>()?;
+        Ok(Self {
+            tokens,
+        })
+    }
+}
+
+pub struct Day18;
+impl Problem for Day18 {
+    type Input = Vec<Expression>;
+    type Part1Output = u64;
+    type Part2Output = u64;
+    type Error = EvaluationError;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let mut result = 0;
+        for expr in input.iter() {
+            result += expr.evaluate()?;
+        }
+        Ok(result)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        let mut result = 0;
+        for expr in input.iter() {
+            result += expr.evaluate_precedence()?;
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,198 @@
+use std::{collections::HashMap, error, fmt, num::ParseIntError, str::FromStr};
+use problem::{BorrowProblem, BorrowedInput, NoSolution, Span, borrowed::str_groups};
+
+#[derive(Clone)]
+pub enum Rule {
+    Literal(char),
+    Sequence(Vec<usize>),
+    Alternate(Vec<usize>, Vec<usize>),
+}
+
+impl Rule {
+    fn matches<'a>(&self, rules: &HashMap<usize, Rule>, s: &'a str) -> Vec<&'a str> {
+        let mut results = Vec::new();
+        match self {
+            &Rule::Literal(c) => {
+                if s.starts_with(c) {
+                    results.push(&s[1..]);
+                }
+            },
+            Rule::Sequence(seq) => {
+                let mut current_matches = vec![s];
+                for rule in seq.iter() {
+                    let mut next_matches = Vec::new();
+                    for s in current_matches {
+                        next_matches.append(&mut rules[rule].matches(rules, s));
+                    }
+                    current_matches = next_matches;
+                }
+                results.append(&mut current_matches);
+            },
+            Rule::Alternate(seq_a, seq_b) => {
+                for &seq in [seq_a, seq_b].iter() {
+                    let mut current_matches = vec![s];
+                    for rule in seq.iter() {
+                        let mut next_matches = Vec::new();
+                        for s in current_matches {
+                            next_matches.append(&mut rules[rule].matches(rules, s));
+                        }
+                        current_matches = next_matches;
+                    }
+                    results.append(&mut current_matches);
+                }
+            }
+        }
+        results
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 3 && s.chars().nth(0).unwrap() == '"' && s.chars().nth(2).unwrap() == '"' {
+            Ok(Rule::Literal(s.chars().nth(1).unwrap()))
+        } else if let Some(bar_pos) = s.find(" | ") {
+            Ok(Rule::Alternate(
+                s[..bar_pos].split(' ').map(|p| p.parse()).collect::<Result<_, _>>()?,
+                s[bar_pos + 3..].split(' ').map(|p| p.parse()).collect::<Result<_, _>>()?,
+            ))
+        } else {
+            Ok(Rule::Sequence(
+                s.split(' ').map(|p| p.parse()).collect::<Result<_, _>>()?
+            ))
+        }
+    }
+}
+
+pub struct Input<'a> {
+    rules: HashMap<usize, Rule>,
+    strings: Vec<&'a str>,
+}
+
+#[derive(Debug)]
+pub enum ParseRuleEntryError {
+    ParseIntError(ParseIntError),
+    MissingRuleDef,
+}
+
+impl fmt::Display for ParseRuleEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseIntError(e) => write!(f, "invalid rule number: {}", e),
+            Self::MissingRuleDef => write!(f, "missing `: ` and rule definition"),
+        }
+    }
+}
+
+impl error::Error for ParseRuleEntryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::ParseIntError(e) => Some(e),
+            Self::MissingRuleDef => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseRuleEntryError {
+    fn from(e: ParseIntError) -> Self {
+        Self::ParseIntError(e)
+    }
+}
+
+fn parse_rule_entry(s: &str) -> Result<(usize, Rule), ParseRuleEntryError> {
+    let mut pieces = s.split(": ");
+    let index = pieces.next().unwrap().parse()?;
+    let rule = pieces.next().ok_or(ParseRuleEntryError::MissingRuleDef)?.parse()?;
+    Ok((index, rule))
+}
+
+#[derive(Debug)]
+pub enum ParseInputError {
+    MissingRules,
+    MissingStrings,
+    InvalidRule {
+        span: Span,
+        error: ParseRuleEntryError,
+    },
+    UnexpectedSection(Span),
+}
+
+impl fmt::Display for ParseInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRules => write!(f, "missing rules"),
+            Self::MissingStrings => write!(f, "missing strings"),
+            Self::InvalidRule { span, error } => write!(f, "invalid rule on line {}: {}", span.line, error),
+            Self::UnexpectedSection(span) => write!(f, "unexpected section at line {}", span.line),
+        }
+    }
+}
+
+impl error::Error for ParseInputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidRule { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> BorrowedInput<'a> for Input<'a> {
+    type Error = ParseInputError;
+
+    fn parse_str(input: &'a str) -> Result<Self, Self::Error> {
+        let mut sections = str_groups(input).into_iter();
+        let rules = sections.next()
+            .ok_or(ParseInputError::MissingRules)?
+            .iter()
+            .map(|line| parse_rule_entry(line.text).map_err(|error| ParseInputError::InvalidRule { span: line.full_span(), error }))
+            .collect::<Result<_, _>>()?;
+        let strings = sections.next()
+            .ok_or(ParseInputError::MissingStrings)?
+            .iter()
+            .map(|line| line.text)
+            .collect();
+        if let Some(extra) = sections.next() {
+            return Err(ParseInputError::UnexpectedSection(extra[0].full_span()));
+        }
+
+        Ok(Self {
+            rules,
+            strings,
+        })
+    }
+
+    fn error_span(error: &Self::Error) -> Option<Span> {
+        match error {
+            ParseInputError::InvalidRule { span, .. } | ParseInputError::UnexpectedSection(span) => Some(span.clone()),
+            ParseInputError::MissingRules | ParseInputError::MissingStrings => None,
+        }
+    }
+}
+
+pub struct Day19;
+impl BorrowProblem for Day19 {
+    type Input<'a> = Input<'a>;
+    type Intermediate<'a> = ();
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = NoSolution;
+
+    const PREPARES: bool = false;
+
+    fn prepare<'a>(_input: &Self::Input<'a>) -> Result<Self::Intermediate<'a>, Self::Error> {
+        Ok(())
+    }
+
+    fn part_1<'a>(input: &Self::Input<'a>, _intermediate: &Self::Intermediate<'a>) -> Result<Self::Part1Output, Self::Error> {
+        Ok(input.strings.iter().map(|s| input.rules[&0].matches(&input.rules, s)).filter(|matches| matches.contains(&"")).count())
+    }
+
+    fn part_2<'a>(input: &Self::Input<'a>, _intermediate: &Self::Intermediate<'a>) -> Result<Self::Part2Output, Self::Error> {
+        let mut rules = input.rules.clone();
+        rules.insert(8, Rule::Alternate(vec![42], vec![42, 8]));
+        rules.insert(11, Rule::Alternate(vec![42, 31], vec![42, 11, 31]));
+        Ok(input.strings.iter().map(|s| rules[&0].matches(&rules, s)).filter(|matches| matches.contains(&"")).count())
+    }
+}
@@ -0,0 +1,58 @@
+use std::{convert::Infallible, str::FromStr};
+use problem::{PatternError, RecordSource, StreamProblem, parse_pattern};
+
+#[derive(Clone)]
+pub struct Input {
+    min_letter: u32,
+    max_letter: u32,
+    letter: char,
+    password: String,
+}
+
+impl Input {
+    fn is_valid(&self) -> bool {
+        let mut count = 0;
+        for c in self.password.chars() {
+            if c == self.letter {
+                count += 1;
+            }
+        }
+        count >= self.min_letter && count <= self.max_letter
+    }
+
+    fn is_valid_2(&self) -> bool {
+        let min_matches = self.password.chars().nth(self.min_letter as usize - 1).unwrap() == self.letter;
+        let max_matches = self.password.chars().nth(self.max_letter as usize - 1).unwrap() == self.letter;
+        min_matches != max_matches
+    }
+}
+
+impl FromStr for Input {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min_letter, max_letter, letter, password) = parse_pattern!("{}-{} {}: {}", s)?;
+        Ok(Input {
+            min_letter,
+            max_letter,
+            letter,
+            password,
+        })
+    }
+}
+
+pub struct Day2;
+impl StreamProblem for Day2 {
+    type Record = Input;
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = Infallible;
+
+    fn part_1(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part1Output, Self::Error> {
+        Ok(records.records().filter(|i| i.is_valid()).count())
+    }
+
+    fn part_2(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part2Output, Self::Error> {
+        Ok(records.records().filter(|i| i.is_valid_2()).count())
+    }
+}
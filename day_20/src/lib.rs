@@ -0,0 +1,271 @@
+use std::{error::Error, fmt, num::ParseIntError};
+use grid::Grid;
+use problem::{FromGroup, Groups, InvalidCharError, Line, NoSolution, ParseGridError, PreparedProblem, Span};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Transform {
+    rotation: u8,
+    reflection: bool,
+}
+
+impl Transform {
+    fn combine(&self, other: &Self) -> Self {
+        if !self.reflection {
+            Self {
+                rotation: (self.rotation + other.rotation) % 4,
+                reflection: other.reflection,
+            }
+        } else {
+            Self {
+                rotation: (self.rotation + 4 - other.rotation) % 4,
+                reflection: self.reflection != other.reflection,
+            }
+        }
+    }
+
+    fn transform(&self, x: i32, y: i32) -> (i32, i32) {
+        if !self.reflection {
+            let tx = [x, -y, -x, y];
+            let ty = [y, x, -y, -x];
+            (tx[self.rotation as usize], ty[self.rotation as usize])
+        } else {
+            let tx = [y, x, -y, -x];
+            let ty = [x, -y, -x, y];
+            (tx[self.rotation as usize], ty[self.rotation as usize])
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Tile {
+    id: u64,
+    sides: [u16; 4],
+    inner: Grid<bool>,
+}
+
+impl Tile {
+    fn reversed_sides(&self) -> [u16; 4] {
+        [
+            self.sides[1].reverse_bits() >> 6,
+            self.sides[0].reverse_bits() >> 6,
+            self.sides[3].reverse_bits() >> 6,
+            self.sides[2].reverse_bits() >> 6,
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseTileError {
+    InvalidTileId {
+        span: Span,
+        inner: ParseIntError,
+    },
+    InvalidGrid(ParseGridError<InvalidCharError>),
+    InvalidSize {
+        span: Span,
+        width: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for ParseTileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTileId { inner, .. } => write!(f, "invalid tile id: {}", inner),
+            Self::InvalidGrid(e) => write!(f, "{}", e),
+            Self::InvalidSize { width, height, .. } => write!(f, "tile is {}x{}, expected 10x10", width, height),
+        }
+    }
+}
+
+impl Error for ParseTileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidTileId { inner, .. } => Some(inner),
+            Self::InvalidGrid(e) => Some(e),
+            Self::InvalidSize { .. } => None,
+        }
+    }
+}
+
+impl FromGroup for Tile {
+    type Err = ParseTileError;
+
+    fn from_group(lines: &[Line]) -> Result<Self, Self::Err> {
+        let title = &lines[0];
+        let id = title.text.get(5..9).unwrap_or("").parse().map_err(|inner| ParseTileError::InvalidTileId { span: title.span(5..9), inner })?;
+
+        let mut grid = Grid::<bool>::from_group(&lines[1..]).map_err(ParseTileError::InvalidGrid)?;
+        if grid.width() != 10 || grid.height() != 10 {
+            return Err(ParseTileError::InvalidSize {
+                span: title.full_span(),
+                width: grid.width(),
+                height: grid.height(),
+            });
+        }
+        grid.flip_vert();
+
+        let mut sides = [0, 0, 0, 0];
+        for i in 0..10 {
+            if *grid.get(9, i) {
+                sides[0] |= 1 << i;
+            }
+            if *grid.get(9 - i, 9) {
+                sides[1] |= 1 << i;
+            }
+            if *grid.get(0, 9 - i) {
+                sides[2] |= 1 << i;
+            }
+            if *grid.get(i, 0) {
+                sides[3] |= 1 << i;
+            }
+        }
+
+        Ok(Tile {
+            id,
+            sides,
+            inner: grid.slice(1, 1, 8, 8),
+        })
+    }
+
+    fn error_span(error: &Self::Err) -> Option<Span> {
+        match error {
+            ParseTileError::InvalidTileId { span, .. } | ParseTileError::InvalidSize { span, .. } => Some(span.clone()),
+            ParseTileError::InvalidGrid(e) => e.span().cloned(),
+        }
+    }
+}
+
+fn reconstruct_image(tiles: &[Tile]) -> Option<(Grid<bool>, Grid<u64>)> {
+    let size = (tiles.len() as f32).sqrt().floor() as usize;
+
+    let mut neighbor_transforms = vec![[None, None, None, None]; tiles.len()];
+
+    for (i, tile) in tiles.iter().enumerate() {
+        for (s, side) in tile.sides.iter().enumerate() {
+            let pair = side.reverse_bits() >> 6;
+            for (j, other) in tiles.iter().enumerate().filter(|&(j, _)| j != i) {
+                if let Some(r) = other.sides.iter().position(|&s| s == pair) {
+                    neighbor_transforms[i][s] = Some((j, Transform { rotation: (s as u8 + 4 - r as u8 + 2) % 4, reflection: false }));
+                    break;
+                } else if let Some(r) = other.reversed_sides().iter().position(|&s| s == pair) {
+                    neighbor_transforms[i][s] = Some((j, Transform { rotation: (4 - s as u8 + r as u8 + 2) % 4, reflection: true }));
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(corner) = neighbor_transforms.iter().position(|ns| ns.iter().filter(|n| n.is_none()).count() == 2) {
+        if let Some(rotation) = match &neighbor_transforms[corner] {
+            [_, _, None, None] => Some(0),
+            [_, None, None, _] => Some(1),
+            [None, None, _, _] => Some(2),
+            [None, _, _, None] => Some(3),
+            _ => None,
+        } {
+            let mut result_image = Grid::new(size * 8, size * 8);
+            let mut result_ids = Grid::new(size, size);
+    
+            let mut queue = vec![((0, 0), corner, Transform { rotation, reflection: false })];
+
+            while let Some(((x, y), index, tile_to_world)) = queue.pop() {
+                if *result_ids.get(x, y) == 0 {
+                    let mut image = tiles[index].inner.clone();
+                    match tile_to_world.rotation {
+                        0 => (),
+                        1 => image.rotate_ccw(),
+                        2 => image.rotate_half(),
+                        3 => image.rotate_cw(),
+                        _ => unreachable!(),
+                    }
+                    if tile_to_world.reflection {
+                        image.flip_vert();
+                        image.rotate_ccw();
+                    }
+
+                    result_image.blit(x * 8, y * 8, &image);
+                    *result_ids.get_mut(x, y) = tiles[index].id;
+
+                    const DX: [i32; 4] = [1, 0, -1, 0];
+                    const DY: [i32; 4] = [0, 1, 0, -1];
+                    for d in 0..4 {
+                        if let Some((nindex, neighbor_to_normal)) = &neighbor_transforms[index][d] {
+                            let (dx, dy) = tile_to_world.transform(DX[d], DY[d]);
+                            let target = neighbor_to_normal.combine(&tile_to_world);
+                            queue.push(((x + dx, y + dy), *nindex, target));
+                        }
+                    }
+                }
+            }
+    
+            Some((result_image, result_ids))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+fn check_pattern(grid: &Grid<bool>, x: i32, y: i32, pattern: &Grid<bool>) -> bool {
+    for (px, py) in pattern.enumerate() {
+        if *pattern.get(px, py) && !grid.get(x + px, y + py) {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day20;
+impl PreparedProblem for Day20 {
+    type Input = Groups<Tile>;
+    type Intermediate = (Grid<bool>, Grid<u64>);
+    type Part1Output = u64;
+    type Part2Output = usize;
+    type Error = NoSolution;
+
+    fn prepare(input: &Self::Input) -> Result<Self::Intermediate, Self::Error> {
+        reconstruct_image(&input.0).ok_or(NoSolution)
+    }
+
+    fn part_1(_input: &Self::Input, (_, ids): &Self::Intermediate) -> Result<Self::Part1Output, Self::Error> {
+        Ok(
+            ids.get(0, 0)
+            * ids.get(ids.width() as i32 - 1, 0)
+            * ids.get(0, ids.height() as i32 - 1)
+            * ids.get(ids.width() as i32 - 1, ids.height() as i32 - 1)
+        )
+    }
+
+    fn part_2(_input: &Self::Input, (image, _): &Self::Intermediate) -> Result<Self::Part2Output, Self::Error> {
+        const LINES: [&str; 3] = [
+            "                  # ",
+            "#    ##    ##    ###",
+            " #  #  #  #  #  #   ",
+        ];
+        let pattern = Grid::new_with(20, 3, |x, y| LINES[2 - y as usize].chars().nth(x as usize).unwrap() == '#');
+
+        let mut image = image.clone();
+
+        let mut pattern_count = 0;
+        'outer: for _ in 0..2 {
+            for _ in 0..4 {
+                for x in 0..image.width() - pattern.width() {
+                    for y in 0..image.height() - pattern.height() {
+                        if check_pattern(&image, x as i32, y as i32, &pattern) {
+                            pattern_count += 1;
+                        }
+                    }
+                }
+                if pattern_count > 0 {
+                    break 'outer;
+                }
+                image.rotate_ccw();
+            }
+            image.flip_vert();
+        }
+
+        Ok(image.enumerate().filter(|&(x, y)| *image.get(x, y)).count() - pattern_count * 15)
+    }
+}
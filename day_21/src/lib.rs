@@ -0,0 +1,142 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+};
+
+use problem::{BorrowProblem, FromLine, LineError, NoSolution};
+
+#[derive(Debug)]
+pub struct Food<'a> {
+    ingredients: HashSet<&'a str>,
+    allergens: HashSet<&'a str>,
+}
+
+#[derive(Debug)]
+pub enum ParseFoodError {
+    NoAllergens,
+}
+
+impl fmt::Display for ParseFoodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoAllergens => write!(f, "missing `(contains ...)` allergen list"),
+        }
+    }
+}
+
+impl Error for ParseFoodError {}
+
+impl LineError for ParseFoodError {}
+
+impl<'a> FromLine<'a> for Food<'a> {
+    type Err = ParseFoodError;
+
+    fn from_line(s: &'a str) -> Result<Self, Self::Err> {
+        let contains = s.find(" (contains ").ok_or(ParseFoodError::NoAllergens)?;
+        let ingredients = s[0..contains].split(' ').collect();
+        let allergens = s[contains + 11..s.len() - 1].split(", ").collect();
+
+        Ok(Self {
+            ingredients,
+            allergens,
+        })
+    }
+}
+
+pub struct Day21;
+impl BorrowProblem for Day21 {
+    type Input<'a> = Vec<Food<'a>>;
+    type Intermediate<'a> = HashMap<&'a str, HashSet<&'a str>>;
+    type Part1Output = usize;
+    type Part2Output = String;
+    type Error = NoSolution;
+
+    fn prepare<'a>(input: &Self::Input<'a>) -> Result<Self::Intermediate<'a>, Self::Error> {
+        let mut candidates = HashMap::new();
+        for food in input.iter() {
+            for ingredient in food.ingredients.iter() {
+                for allergen in food.allergens.iter() {
+                    candidates.entry(*ingredient).or_insert(HashSet::new()).insert(*allergen);
+                }
+            }
+        }
+
+        for i in 0..input.len() {
+            for j in (0..input.len()).filter(|&n| n != i) {
+                let common_allergens = input[i].allergens.intersection(&input[j].allergens).collect::<Vec<_>>();
+                for ingredient in input[i].ingredients.difference(&input[j].ingredients) {
+                    for &allergen in common_allergens.iter() {
+                        candidates.get_mut(ingredient).unwrap().remove(allergen);
+                    }
+                }
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    fn part_1<'a>(input: &Self::Input<'a>, candidates: &Self::Intermediate<'a>) -> Result<Self::Part1Output, Self::Error> {
+        let mut candidates = candidates.clone();
+
+        let resolved = candidates.iter()
+            .filter_map(|(k, v)| {
+                if v.len() == 1 {
+                    Some((*k, *v.iter().next().unwrap()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        for (resolved_ingredient, resolved_allergen) in resolved.iter() {
+            for (ingredient, candidates) in candidates.iter_mut() {
+                if ingredient != resolved_ingredient {
+                    candidates.remove(resolved_allergen);
+                }
+            }
+        }
+
+        let non_allergens = candidates.iter().filter_map(|(k, v)| if v.is_empty() { Some(k) } else { None }).collect::<HashSet<_>>();
+
+        Ok(input.iter().map(|food| food.ingredients.iter().filter(|i| non_allergens.contains(i)).count()).sum())
+    }
+
+    fn part_2<'a>(_input: &Self::Input<'a>, candidates: &Self::Intermediate<'a>) -> Result<Self::Part2Output, Self::Error> {
+        let mut candidates = candidates.clone();
+        loop {
+            let mut changed = false;
+            let resolved = candidates.iter()
+                .filter_map(|(k, v)| {
+                    if v.len() == 1 {
+                        Some((*k, *v.iter().next().unwrap()))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+            for (resolved_ingredient, resolved_allergen) in resolved.iter() {
+                for (ingredient, candidates) in candidates.iter_mut() {
+                    if ingredient != resolved_ingredient {
+                        changed = changed || candidates.remove(resolved_allergen);
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut resolved = candidates.iter()
+            .filter_map(|(k, v)| {
+                if v.len() == 1 {
+                    Some((*v.iter().next().unwrap(), *k))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        resolved.sort();
+
+        Ok(resolved.iter().fold(String::new(), |acc, (_, ingredient)| if acc.is_empty() { ingredient.to_string() } else { format!("{},{}", acc, ingredient) }))
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::{HashSet, VecDeque};
+use problem::{NoSolution, Problem, ProblemInput};
+
+#[derive(ProblemInput)]
+#[problem_input(sections)]
+pub struct Input {
+    #[problem_input(title = "Player 1:")]
+    player_1: Vec<u32>,
+    #[problem_input(title = "Player 2:")]
+    player_2: Vec<u32>,
+}
+
+pub enum RecursiveCombatWinner {
+    Player1(VecDeque<u32>),
+    Player2(VecDeque<u32>),
+}
+
+// returns "did player 1 win"
+fn recursive_combat(mut player_1: VecDeque<u32>, mut player_2: VecDeque<u32>) -> RecursiveCombatWinner {
+    let mut previous_states = HashSet::new();
+
+    while !player_1.is_empty() && !player_2.is_empty() {
+        if !previous_states.insert((player_1.clone(), player_2.clone())) {
+            return RecursiveCombatWinner::Player1(player_1);
+        } else {
+            let card_1 = player_1.pop_front().unwrap();
+            let card_2 = player_2.pop_front().unwrap();
+
+            if card_1 as usize <= player_1.len() && card_2 as usize <= player_2.len() {
+                let player_1_deck = player_1.iter().take(card_1 as usize).cloned().collect();
+                let player_2_deck = player_2.iter().take(card_2 as usize).cloned().collect();
+                match recursive_combat(player_1_deck, player_2_deck) {
+                    RecursiveCombatWinner::Player1(_) => {
+                        player_1.push_back(card_1);
+                        player_1.push_back(card_2);
+                    },
+                    RecursiveCombatWinner::Player2(_) => {
+                        player_2.push_back(card_2);
+                        player_2.push_back(card_1);
+                    },
+                }
+            } else if card_1 > card_2 {
+                player_1.push_back(card_1);
+                player_1.push_back(card_2);
+            } else {
+                player_2.push_back(card_2);
+                player_2.push_back(card_1);
+            }
+        }
+    }
+
+    if !player_1.is_empty() {
+        RecursiveCombatWinner::Player1(player_1)
+    } else {
+        RecursiveCombatWinner::Player2(player_2)
+    }
+}
+
+pub struct Day22;
+impl Problem for Day22 {
+    type Input = Input;
+    type Part1Output = u32;
+    type Part2Output = u32;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let mut player_1 = input.player_1.iter().cloned().collect::<VecDeque<_>>();
+        let mut player_2 = input.player_2.iter().cloned().collect::<VecDeque<_>>();
+
+        while !player_1.is_empty() && !player_2.is_empty() {
+            let card_1 = player_1.pop_front().unwrap();
+            let card_2 = player_2.pop_front().unwrap();
+
+            if card_1 > card_2 {
+                player_1.push_back(card_1);
+                player_1.push_back(card_2);
+            } else {
+                player_2.push_back(card_2);
+                player_2.push_back(card_1);
+            }
+        }
+
+        let winner = if !player_1.is_empty() { player_1 } else { player_2 };
+        Ok(winner.iter().rev().enumerate().map(|(i, v)| (i as u32 + 1) * v).sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let winner = match recursive_combat(input.player_1.iter().cloned().collect(), input.player_2.iter().cloned().collect()) {
+            RecursiveCombatWinner::Player1(deck) => deck,
+            RecursiveCombatWinner::Player2(deck) => deck,
+        };
+        Ok(winner.iter().rev().enumerate().map(|(i, v)| (i as u32 + 1) * v).sum())
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+use problem::{NoSolution, ProblemInput, Problem, Submission};
+
+#[derive(ProblemInput)]
+pub struct Input {
+    card: u32,
+    door: u32,
+}
+
+fn transform(input: u32, subject: u32, mod_size: u32) -> u32 {
+    ((input as u64 * subject as u64) % mod_size as u64) as u32
+}
+
+// There's no second puzzle on the last day, its star comes from finishing all the others.
+pub struct AllDone;
+
+impl fmt::Display for AllDone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "All done!")
+    }
+}
+
+impl Submission for AllDone {
+    fn submission(&self) -> Option<String> {
+        None
+    }
+}
+
+pub struct Day25;
+impl Problem for Day25 {
+    type Input = Input;
+    type Part1Output = u32;
+    type Part2Output = AllDone;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        const SUBJECT: u32 = 7;
+        const MOD_SIZE: u32 = 20201227;
+
+        let mut value = 1;
+        let mut result = 1;
+        while value != input.card {
+            value = transform(value, SUBJECT, MOD_SIZE);
+            result = transform(result, input.door, MOD_SIZE);
+        }
+
+        Ok(result)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Ok(AllDone)
+    }
+}
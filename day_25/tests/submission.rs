@@ -0,0 +1,12 @@
+use day_25::Day25;
+use problem::{PartOutcome, solve};
+
+#[test]
+fn only_part_1_has_an_answer_to_submit() {
+    let report = solve::<Day25>(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/example1.txt"), Default::default());
+    let answer = |outcome: &Option<PartOutcome>| outcome.as_ref().and_then(PartOutcome::answer).cloned().unwrap();
+    let (part_1, part_2) = (answer(&report.part_1), answer(&report.part_2));
+    assert_eq!(part_1.submission, Some(part_1.value));
+    assert_eq!(part_2.value, "All done!");
+    assert_eq!(part_2.submission, None);
+}
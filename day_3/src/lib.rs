@@ -0,0 +1,54 @@
+use std::convert::Infallible;
+use grid::Grid;
+use problem::{FromChar, InvalidCharError, Problem};
+
+pub enum Spot {
+    Empty,
+    Tree,
+}
+
+impl FromChar for Spot {
+    type Err = InvalidCharError;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '.' => Ok(Spot::Empty),
+            '#' => Ok(Spot::Tree),
+            c => Err(InvalidCharError(c)),
+        }
+    }
+}
+
+fn hit_trees(input: &Grid<Spot>, slope_x: usize, slope_y: usize) -> usize {
+    let mut trees = 0;
+    let mut x = 0;
+    let mut y = 0;
+    while y < input.height() {
+        if let Spot::Tree = input.get((x % input.width()) as i32, y as i32) { trees += 1 }
+        x += slope_x;
+        y += slope_y;
+    }
+    trees
+}
+
+pub struct Day3;
+impl Problem for Day3 {
+    type Input = Grid<Spot>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = Infallible;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Ok(hit_trees(input, 3, 1))
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Ok(
+            hit_trees(input, 1, 1)
+            * hit_trees(input, 3, 1)
+            * hit_trees(input, 5, 1)
+            * hit_trees(input, 7, 1)
+            * hit_trees(input, 1, 2)
+        )
+    }
+}
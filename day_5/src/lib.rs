@@ -0,0 +1,90 @@
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+use problem::{LineError, NoSolution, RecordSource, StreamProblem};
+
+#[derive(Clone)]
+pub struct Position {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Debug)]
+pub enum ParsePositionError {
+    InvalidLength(usize),
+    InvalidCharacter {
+        column: usize,
+        c: char,
+    },
+}
+
+impl fmt::Display for ParsePositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(length) => write!(f, "expected 10 characters, found {}", length),
+            Self::InvalidCharacter { c, .. } => write!(f, "invalid character `{}`", c),
+        }
+    }
+}
+
+impl Error for ParsePositionError {}
+
+impl LineError for ParsePositionError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            Self::InvalidLength(_) => None,
+            Self::InvalidCharacter { column, c } => Some(*column..*column + c.len_utf8()),
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = ParsePositionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 10 {
+            Err(ParsePositionError::InvalidLength(s.len()))
+        } else {
+            Ok(Self {
+                x: s[7..10].char_indices().try_fold(0, |acc, (i, c)| {
+                    Ok(acc << 1 | match c {
+                        'L' => 0,
+                        'R' => 1,
+                        c => return Err(ParsePositionError::InvalidCharacter { column: 7 + i, c }),
+                    })
+                })?,
+                y: s[0..7].char_indices().try_fold(0, |acc, (column, c)| {
+                    Ok(acc << 1 | match c {
+                        'F' => 0,
+                        'B' => 1,
+                        c => return Err(ParsePositionError::InvalidCharacter { column, c }),
+                    })
+                })?,
+            })
+        }
+    }
+}
+
+pub struct Day5;
+impl StreamProblem for Day5 {
+    type Record = Position;
+    type Part1Output = u32;
+    type Part2Output = usize;
+    type Error = NoSolution;
+
+    fn part_1(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part1Output, Self::Error> {
+        records.records().map(|i| i.x + i.y * 8).max().ok_or(NoSolution)
+    }
+
+    fn part_2(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part2Output, Self::Error> {
+        let mut found = vec![false; 128 * 8];
+        for i in records.records() {
+            let index = i.x + i.y * 8;
+            found[index as usize] = true;
+        }
+        for i in 1..128 * 8 - 1 {
+            if !found[i] && found[i - 1] && found[i + 1] {
+                return Ok(i);
+            }
+        }
+        Err(NoSolution)
+    }
+}
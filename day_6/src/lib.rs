@@ -0,0 +1,32 @@
+use std::{convert::Infallible, str::FromStr};
+use problem::{Groups, NoSolution, Problem};
+
+pub struct Answers(u32);
+
+impl FromStr for Answers {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answers(s.chars().fold(0, |acc, c| acc | (1 << (c as usize - 'a' as usize)))))
+    }
+}
+
+pub struct Day6;
+impl Problem for Day6 {
+    type Input = Groups<Vec<Answers>>;
+    type Part1Output = u32;
+    type Part2Output = u32;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        Ok(input.0.iter()
+            .map(|group| group.iter().fold(0, |acc, i| acc | i.0).count_ones())
+            .sum())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        Ok(input.0.iter()
+            .map(|group| group.iter().fold(0xffffffffu32, |acc, i| acc & i.0).count_ones())
+            .sum())
+    }
+}
@@ -0,0 +1,306 @@
+use std::str::FromStr;
+use std::{error::Error, fmt, ops::Range};
+use std::collections::{HashMap, HashSet};
+use problem::{LineError, NoSolution, PatternError, Problem, parse_pattern};
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub enum Modifier {
+    Light,
+    Dark,
+    Bright,
+    Muted,
+    Shiny,
+    Vibrant,
+    Faded,
+    Dotted,
+    Pale,
+    Striped,
+    Posh,
+    Wavy,
+    Drab,
+    Clear,
+    Dull,
+    Plaid,
+    Mirrored,
+    Dim,
+}
+
+#[derive(Debug)]
+pub struct ParseModifierError(pub String);
+
+impl fmt::Display for ParseModifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown modifier `{}`", self.0)
+    }
+}
+
+impl Error for ParseModifierError {}
+
+impl FromStr for Modifier {
+    type Err = ParseModifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Modifier::*;
+         Ok(match s {
+             "light" => Light,
+             "dark" => Dark,
+             "bright" => Bright,
+             "muted" => Muted,
+             "shiny" => Shiny,
+             "vibrant" => Vibrant,
+             "faded" => Faded,
+             "dotted" => Dotted,
+             "pale" => Pale,
+             "striped" => Striped,
+             "posh" => Posh,
+             "wavy" => Wavy,
+             "drab" => Drab,
+             "clear" => Clear,
+             "dull" => Dull,
+             "plaid" => Plaid,
+             "mirrored" => Mirrored,
+             "dim" => Dim,
+             s => return Err(ParseModifierError(s.to_string())),
+         })
+    }
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub enum Color {
+    Red,
+    Orange,
+    White,
+    Yellow,
+    Gold,
+    Olive,
+    Plum,
+    Blue,
+    Black,
+    Turquoise,
+    Cyan,
+    Teal,
+    Tan,
+    Tomato,
+    Coral,
+    Bronze,
+    Purple,
+    Crimson,
+    Beige,
+    Salmon,
+    Maroon,
+    Lavender,
+    Lime,
+    Indigo,
+    Chartreuse,
+    Magenta,
+    Silver,
+    Violet,
+    Brown,
+    Aqua,
+    Green,
+    Gray,
+    Fuchsia,
+}
+
+#[derive(Debug)]
+pub struct ParseColorError(pub String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown color `{}`", self.0)
+    }
+}
+
+impl Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Color::*;
+         Ok(match s {
+             "red" => Red,
+             "orange" => Orange,
+             "white" => White,
+             "yellow" => Yellow,
+             "gold" => Gold,
+             "olive" => Olive,
+             "plum" => Plum,
+             "blue" => Blue,
+             "black" => Black,
+             "turquoise" => Turquoise,
+             "cyan" => Cyan,
+             "teal" => Teal,
+             "tan" => Tan,
+             "tomato" => Tomato,
+             "coral" => Coral,
+             "bronze" => Bronze,
+             "purple" => Purple,
+             "crimson" => Crimson,
+             "beige" => Beige,
+             "salmon" => Salmon,
+             "maroon" => Maroon,
+             "lavender" => Lavender,
+             "lime" => Lime,
+             "indigo" => Indigo,
+             "chartreuse" => Chartreuse,
+             "magenta" => Magenta,
+             "silver" => Silver,
+             "violet" => Violet,
+             "brown" => Brown,
+             "aqua" => Aqua,
+             "green" => Green,
+             "gray" => Gray,
+             "fuchsia" => Fuchsia,
+             s => return Err(ParseColorError(s.to_string())),
+         })
+    }
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Bag {
+    pub modifier: Modifier,
+    pub color: Color,
+}
+
+impl FromStr for Bag {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (modifier, color) = parse_pattern!("{} {}", s)?;
+        Ok(Bag {
+            modifier,
+            color,
+        })
+    }
+}
+
+pub struct Rule {
+    pub outer: Bag,
+    pub inner: Vec<(usize, Bag)>,
+}
+
+#[derive(Debug)]
+pub enum ParseRuleError {
+    PatternError(PatternError),
+    InvalidContents {
+        offset: usize,
+        error: PatternError,
+    },
+}
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PatternError(e) => write!(f, "{}", e),
+            Self::InvalidContents { error, .. } => write!(f, "invalid bag contents: {}", error),
+        }
+    }
+}
+
+impl Error for ParseRuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::PatternError(e) => Some(e),
+            Self::InvalidContents { error, .. } => Some(error),
+        }
+    }
+}
+
+impl LineError for ParseRuleError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            Self::PatternError(e) => e.columns(),
+            Self::InvalidContents { offset, error } => error.columns().map(|c| c.start + offset..c.end + offset),
+        }
+    }
+}
+
+impl From<PatternError> for ParseRuleError {
+    fn from(e: PatternError) -> Self {
+        Self::PatternError(e)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (outer, contents): (Bag, String) = parse_pattern!("{} bags contain {}.", s)?;
+
+        let mut offset = s.len() - 1 - contents.len();
+        let inner = if contents == "no other bags" {
+            Vec::new()
+        } else {
+            contents.split(", ")
+                .map(|bag| {
+                    let result = parse_pattern!("{} {} bag", bag.strip_suffix('s').unwrap_or(bag))
+                        .map_err(|error| ParseRuleError::InvalidContents {
+                            offset,
+                            error,
+                        });
+                    offset += bag.len() + 2;
+                    result
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Self {
+            outer,
+            inner,
+        })
+    }
+}
+
+pub struct Day7;
+impl Problem for Day7 {
+    type Input = Vec<Rule>;
+    type Part1Output = usize;
+    type Part2Output = usize;
+    type Error = NoSolution;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let mut graph = HashMap::new();
+
+        for i in input {
+            if !graph.contains_key(&i.outer) {
+                graph.insert(i.outer.clone(), Vec::new());
+            }
+
+            for inner in i.inner.iter() {
+                graph.entry(inner.1.clone()).or_insert(Vec::new()).push(i.outer.clone());
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut frontier = graph.get(&Bag { modifier: Modifier::Shiny, color: Color::Gold }).unwrap().clone();
+
+        while let Some(next) = frontier.pop() {
+            if visited.insert(next.clone()) {
+                for outer in graph.get(&next).unwrap() {
+                    frontier.push(outer.clone());
+                }
+            }
+        }
+
+        Ok(visited.len())
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        let mut graph = HashMap::new();
+        for i in input {
+            graph.insert(i.outer.clone(), i.inner.clone());
+        }
+
+        let mut frontier = graph.get(&Bag { modifier: Modifier::Shiny, color: Color::Gold }).unwrap().clone();
+        let mut total = 0;
+        while let Some(next) = frontier.pop() {
+            total += next.0;
+            for (amount, bag) in graph.get(&next.1).unwrap() {
+                frontier.push((amount * next.0, bag.clone()))
+            }
+        }
+
+        Ok(total)
+    }
+}
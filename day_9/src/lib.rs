@@ -0,0 +1,66 @@
+use std::collections::VecDeque;
+use problem::{NoSolution, RecordSource, StreamProblem};
+use smallbitvec::SmallBitVec;
+
+fn solve_2(values: &[i64], target: i64) -> Option<(i64, i64)> {
+    let half = target / 2 + 1;
+    let mut bits = SmallBitVec::from_elem(half as usize, false);
+    for &value in values.iter() {
+        let index = if value < half { value } else { target - value };
+        if index >= 0 {
+            if bits[index as usize] {
+                return Some((index, target - index))
+            } else {
+                bits.set(index as usize, true);
+            }
+        }
+    }
+    None
+}
+
+const PREAMBLE_LEN: usize = 25;
+
+fn find_invalid(values: impl Iterator<Item = i64>) -> Option<i64> {
+    let mut window = VecDeque::with_capacity(PREAMBLE_LEN);
+    for value in values {
+        if window.len() == PREAMBLE_LEN {
+            if solve_2(window.make_contiguous(), value).is_none() {
+                return Some(value);
+            }
+            window.pop_front();
+        }
+        window.push_back(value);
+    }
+    None
+}
+
+pub struct Day9;
+impl StreamProblem for Day9 {
+    type Record = i64;
+    type Part1Output = i64;
+    type Part2Output = i64;
+    type Error = NoSolution;
+
+    fn part_1(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part1Output, Self::Error> {
+        find_invalid(records.records()).ok_or(NoSolution)
+    }
+
+    fn part_2(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part2Output, Self::Error> {
+        let target = Self::part_1(records)?;
+
+        let mut values = records.records();
+        let mut range = VecDeque::new();
+        let mut total = 0;
+        loop {
+            if total < target {
+                let value = values.next().ok_or(NoSolution)?;
+                total += value;
+                range.push_back(value);
+            } else if total > target {
+                total -= range.pop_front().unwrap();
+            } else {
+                return Ok(range.iter().min().unwrap() + range.iter().max().unwrap());
+            }
+        }
+    }
+}
@@ -27,6 +27,16 @@ impl<T> Grid<T> {
         }
     }
 
+    pub fn from_vec(width: usize, height: usize, values: Vec<T>) -> Self {
+        assert_eq!(values.len(), width * height);
+
+        Self {
+            width,
+            height,
+            values,
+        }
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
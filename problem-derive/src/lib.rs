@@ -0,0 +1,316 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Error, Field, Fields, LitStr, Path, Result, parse_macro_input, spanned::Spanned};
+
+/// Derives `ProblemInput` for a struct with named fields, along with a `Parse{Name}Error` type.
+///
+/// By default each field is parsed from one line with `FromStr`, and any non-blank line after the last
+/// field is an error. With `#[problem_input(sections)]` on the struct, each field is parsed from one
+/// blank-line-separated section with `FromGroup`.
+///
+/// Field attributes:
+/// - `with = path`: parse a line with `fn(&str) -> Result<T, E>` instead of `FromStr`
+/// - `separator = ","`: split the line and collect each piece into the field
+/// - `title = "..."`: (sections) require the section to start with this line
+/// - `line`: (sections) the section is a single line
+/// - `each_line`: (sections) parse every line of the section and collect them into the field
+/// - `group_with = path`: (sections) parse the section with `fn(&[Line]) -> Result<T, E>`
+#[proc_macro_derive(ProblemInput, attributes(problem_input))]
+pub fn derive_problem_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Lines,
+    Sections,
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    with: Option<Path>,
+    group_with: Option<Path>,
+    separator: Option<LitStr>,
+    title: Option<LitStr>,
+    line: bool,
+    each_line: bool,
+}
+
+fn problem_input_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("problem_input"))
+}
+
+fn parse_mode(input: &DeriveInput) -> Result<Mode> {
+    let mut mode = Mode::Lines;
+    for attr in problem_input_attrs(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("lines") {
+                mode = Mode::Lines;
+                Ok(())
+            } else if meta.path.is_ident("sections") {
+                mode = Mode::Sections;
+                Ok(())
+            } else {
+                Err(meta.error("expected `lines` or `sections`"))
+            }
+        })?;
+    }
+    Ok(mode)
+}
+
+fn parse_field_attrs(field: &Field, mode: Mode) -> Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    for attr in problem_input_attrs(&field.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                attrs.with = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("group_with") {
+                attrs.group_with = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("separator") {
+                attrs.separator = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("title") {
+                attrs.title = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("line") {
+                attrs.line = true;
+            } else if meta.path.is_ident("each_line") {
+                attrs.each_line = true;
+            } else {
+                return Err(meta.error("expected `with`, `group_with`, `separator`, `title`, `line` or `each_line`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let error = |message: &str| Err(Error::new(field.span(), message));
+    match mode {
+        Mode::Lines => {
+            if attrs.group_with.is_some() || attrs.title.is_some() || attrs.line || attrs.each_line {
+                return error("`group_with`, `title`, `line` and `each_line` require `#[problem_input(sections)]`");
+            }
+        },
+        Mode::Sections => {
+            if attrs.line && attrs.each_line {
+                return error("`line` and `each_line` are mutually exclusive");
+            }
+            if attrs.group_with.is_some() && (attrs.with.is_some() || attrs.line || attrs.each_line) {
+                return error("`group_with` parses the whole section and can't be combined with `with`, `line` or `each_line`");
+            }
+            if attrs.with.is_some() && !attrs.line && !attrs.each_line {
+                return error("`with` parses a single line, use `line`, `each_line` or `group_with`");
+            }
+            if attrs.separator.is_some() && !attrs.line {
+                return error("`separator` requires `line`");
+            }
+        },
+    }
+
+    Ok(attrs)
+}
+
+fn parse_line(attrs: &FieldAttrs, line: TokenStream2) -> TokenStream2 {
+    let parser = match &attrs.with {
+        Some(with) => quote! { #with },
+        None => quote! { ::problem::derive::from_str },
+    };
+    match &attrs.separator {
+        Some(separator) => quote! { ::problem::derive::parse_split(#line, #separator, #parser) },
+        None => quote! { ::problem::derive::parse_line(#line, #parser) },
+    }
+}
+
+fn derive(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(input.span(), "`ProblemInput` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new(input.span(), "`ProblemInput` can only be derived for structs")),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(input.generics.span(), "`ProblemInput` can't be derived for generic structs"));
+    }
+
+    let mode = parse_mode(input)?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let error = format_ident!("Parse{}Error", name);
+
+    let mut parse_fields = Vec::new();
+    let mut field_names = Vec::new();
+    for field in fields.iter() {
+        let attrs = parse_field_attrs(field, mode)?;
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let field_name = ident.to_string();
+
+        let parse = match mode {
+            Mode::Lines => {
+                let parse = parse_line(&attrs, quote! { &line });
+                quote! {
+                    let line = lines.next().ok_or(#error::Missing(#field_name))??;
+                    #parse
+                }
+            },
+            Mode::Sections => {
+                let title = attrs.title.as_ref().map(|title| quote! {
+                    if section[0].text != #title {
+                        return Err(#error::ExpectedTitle {
+                            field: #field_name,
+                            title: #title,
+                            span: section[0].full_span(),
+                        });
+                    }
+                });
+                let skip = if attrs.title.is_some() { 1usize } else { 0usize };
+                let parse = if let Some(group_with) = &attrs.group_with {
+                    quote! { ::problem::derive::parse_group_with(lines, &section, #group_with) }
+                } else if attrs.each_line {
+                    let parser = match &attrs.with {
+                        Some(with) => quote! { #with },
+                        None => quote! { ::problem::derive::from_str },
+                    };
+                    quote! { ::problem::derive::parse_each_line(lines, #parser) }
+                } else if attrs.line {
+                    let parse = parse_line(&attrs, quote! { line });
+                    quote! {
+                        {
+                            let line = lines.first().ok_or(#error::Missing(#field_name))?;
+                            if let Some(extra) = lines.get(1) {
+                                return Err(#error::UnexpectedLine(extra.full_span()));
+                            }
+                            #parse
+                        }
+                    }
+                } else {
+                    quote! { ::problem::derive::parse_group::<#ty>(lines, &section) }
+                };
+                quote! {
+                    let section = sections.next().ok_or(#error::Missing(#field_name))?;
+                    #title
+                    let lines = &section[#skip..];
+                    #parse
+                }
+            },
+        };
+
+        parse_fields.push(quote! {
+            let #ident: #ty = {
+                #parse
+            }.map_err(|(span, error)| #error::Invalid {
+                field: #field_name,
+                span,
+                error,
+            })?;
+        });
+        field_names.push(ident);
+    }
+
+    let (sections_variants, sections_display, sections_spans) = if mode == Mode::Sections {
+        (
+            quote! {
+                UnexpectedSection(::problem::Span),
+                ExpectedTitle {
+                    field: &'static str,
+                    title: &'static str,
+                    span: ::problem::Span,
+                },
+            },
+            quote! {
+                Self::UnexpectedSection(span) => write!(f, "unexpected section at line {}", span.line),
+                Self::ExpectedTitle { field, title, span } => write!(f, "expected `{}` before {} on line {}", title, field, span.line),
+            },
+            quote! {
+                #error::UnexpectedSection(span) | #error::ExpectedTitle { span, .. } => Some(span.clone()),
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
+    let parse_body = match mode {
+        Mode::Lines => quote! {
+            let mut lines = ::problem::derive::lines(reader);
+            #(#parse_fields)*
+            for line in lines {
+                let line = line?;
+                if !line.text.trim().is_empty() {
+                    return Err(#error::UnexpectedLine(line.full_span()));
+                }
+            }
+        },
+        Mode::Sections => quote! {
+            let mut sections = ::problem::derive::read_groups(reader)?.into_iter();
+            #(#parse_fields)*
+            if let Some(extra) = sections.next() {
+                return Err(#error::UnexpectedSection(extra[0].full_span()));
+            }
+        },
+    };
+
+    Ok(quote! {
+        #[derive(Debug)]
+        #vis enum #error {
+            IoError(::std::io::Error),
+            Missing(&'static str),
+            Invalid {
+                field: &'static str,
+                span: ::problem::Span,
+                error: ::std::boxed::Box<dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync>,
+            },
+            UnexpectedLine(::problem::Span),
+            #sections_variants
+        }
+
+        impl ::std::convert::From<::std::io::Error> for #error {
+            fn from(e: ::std::io::Error) -> Self {
+                Self::IoError(e)
+            }
+        }
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    Self::IoError(e) => write!(f, "failed to read line: {}", e),
+                    Self::Missing(field) => write!(f, "missing {}", field),
+                    Self::Invalid { field, span, error } => write!(f, "invalid {} on line {}: {}", field, span.line, error),
+                    Self::UnexpectedLine(span) => write!(f, "unexpected line {}", span.line),
+                    #sections_display
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error {
+            fn source(&self) -> ::std::option::Option<&(dyn ::std::error::Error + 'static)> {
+                match self {
+                    Self::IoError(e) => Some(e),
+                    Self::Invalid { error, .. } => Some(error.as_ref()),
+                    _ => None,
+                }
+            }
+        }
+
+        impl ::problem::ProblemInput for #name {
+            type Error = #error;
+
+            fn parse<R: ::std::io::BufRead>(reader: R) -> ::std::result::Result<Self, Self::Error> {
+                #parse_body
+                Ok(Self {
+                    #(#field_names,)*
+                })
+            }
+
+            fn error_span(error: &Self::Error) -> ::std::option::Option<::problem::Span> {
+                match error {
+                    #error::Invalid { span, .. } | #error::UnexpectedLine(span) => Some(span.clone()),
+                    #sections_spans
+                    _ => None,
+                }
+            }
+        }
+    })
+}
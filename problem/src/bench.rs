@@ -0,0 +1,165 @@
+use std::{
+    fmt::{self, Write},
+    fs,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use crate::{PreparedProblem, ProblemInput, SolveError, json};
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            iterations: 10,
+        }
+    }
+}
+
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples.iter()
+            .map(|s| {
+                let diff = s.as_secs_f64() - mean.as_secs_f64();
+                diff * diff
+            })
+            .sum::<f64>() / n as f64;
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+
+        Self {
+            samples: n,
+            min: samples[0],
+            median,
+            mean,
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        write!(
+            out,
+            "{{\"samples\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"p95_ns\":{},\"std_dev_ns\":{}}}",
+            self.samples,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.p95.as_nanos(),
+            self.std_dev.as_nanos(),
+        )
+    }
+}
+
+pub struct BenchReport {
+    pub path: String,
+    pub warmup: usize,
+    pub parse: Stats,
+    pub prepare: Option<Stats>,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out).unwrap();
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        out.push_str("{\"path\":");
+        json::write_string(out, &self.path)?;
+        write!(out, ",\"warmup\":{},\"parse\":", self.warmup)?;
+        self.parse.write_json(out)?;
+        out.push_str(",\"prepare\":");
+        match &self.prepare {
+            Some(prepare) => prepare.write_json(out)?,
+            None => out.push_str("null"),
+        }
+        out.push_str(",\"part_1\":");
+        self.part_1.write_json(out)?;
+        out.push_str(",\"part_2\":");
+        self.part_2.write_json(out)?;
+        out.push('}');
+        Ok(())
+    }
+}
+
+fn sample<T, E>(options: &BenchOptions, mut f: impl FnMut() -> Result<T, E>) -> Result<Stats, E> {
+    for _ in 0..options.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations {
+        let start = Instant::now();
+        let result = f()?;
+        samples.push(Instant::now().duration_since(start));
+        black_box(result);
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+// Samples each stage in turn, `parse` is run once more to get the input the later stages use.
+pub(crate) fn bench_stages<I, M, T1, T2, PE, E>(
+    path: &str,
+    options: &BenchOptions,
+    prepares: bool,
+    mut parse: impl FnMut() -> Result<I, PE>,
+    prepare: impl Fn(&I) -> Result<M, E>,
+    part_1: impl Fn(&I, &M) -> Result<T1, E>,
+    part_2: impl Fn(&I, &M) -> Result<T2, E>,
+) -> Result<BenchReport, SolveError<PE, E>> {
+    assert!(options.iterations > 0);
+
+    let parse_stats = sample(options, &mut parse).map_err(SolveError::ParseInput)?;
+
+    let input = parse().map_err(SolveError::ParseInput)?;
+    let prepare_stats = if prepares {
+        Some(sample(options, || prepare(&input)).map_err(SolveError::Prepare)?)
+    } else {
+        None
+    };
+
+    let intermediate = prepare(&input).map_err(SolveError::Prepare)?;
+    let part_1 = sample(options, || part_1(&input, &intermediate)).map_err(SolveError::SolvePart1)?;
+    let part_2 = sample(options, || part_2(&input, &intermediate)).map_err(SolveError::SolvePart2)?;
+
+    Ok(BenchReport {
+        path: path.to_string(),
+        warmup: options.warmup,
+        parse: parse_stats,
+        prepare: prepare_stats,
+        part_1,
+        part_2,
+    })
+}
+
+pub fn bench<P: PreparedProblem>(path: &str, options: &BenchOptions) -> Result<BenchReport, SolveError<<P::Input as ProblemInput>::Error, P::Error>> {
+    let contents = fs::read(path)?;
+    bench_stages(path, options, P::PREPARES, || P::Input::parse(contents.as_slice()), P::prepare, P::part_1, P::part_2)
+}
@@ -0,0 +1,211 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fs,
+    io,
+    ops::Range,
+    str,
+};
+use crate::{
+    ErasedError,
+    LineError,
+    ParseLinesError,
+    Plan,
+    STDIN_PATH,
+    SolveError,
+    SolveOptions,
+    SolveReport,
+    Span,
+    Submission,
+    Watch,
+    bench::{BenchOptions, BenchReport, bench_stages},
+    parse_measured,
+    read_input,
+    run_with,
+    solve_parsed,
+    solve_with,
+    span::line_span,
+};
+
+pub trait BorrowedInput<'a>: Sized {
+    type Error: Error;
+
+    fn parse_str(input: &'a str) -> Result<Self, Self::Error>;
+
+    fn error_span(_error: &Self::Error) -> Option<Span> {
+        None
+    }
+}
+
+pub trait FromLine<'a>: Sized {
+    type Err: LineError;
+
+    fn from_line(line: &'a str) -> Result<Self, Self::Err>;
+}
+
+impl<'a> FromLine<'a> for &'a str {
+    type Err = Infallible;
+
+    fn from_line(line: &'a str) -> Result<Self, Self::Err> {
+        Ok(line)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct StrLine<'a> {
+    pub number: usize,
+    pub offset: usize,
+    pub text: &'a str,
+}
+
+impl StrLine<'_> {
+    pub fn span(&self, columns: Range<usize>) -> Span {
+        line_span(self.number, self.offset, self.text, columns)
+    }
+
+    pub fn full_span(&self) -> Span {
+        self.span(0..self.text.len())
+    }
+}
+
+pub fn str_lines(input: &str) -> impl Iterator<Item = StrLine<'_>> {
+    let mut offset = 0;
+    input.split_inclusive('\n').enumerate().map(move |(i, text)| {
+        let line = StrLine {
+            number: i + 1,
+            offset,
+            text: text.strip_suffix('\n').map_or(text, |text| text.strip_suffix('\r').unwrap_or(text)),
+        };
+        offset += text.len();
+        line
+    })
+}
+
+pub fn str_groups(input: &str) -> Vec<Vec<StrLine<'_>>> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    for line in str_lines(input) {
+        if line.text.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = Vec::new();
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+pub fn parse_str_line<'a, T: FromLine<'a>>(line: &StrLine<'a>) -> Result<T, ParseLinesError<T::Err>> {
+    T::from_line(line.text).map_err(|error| ParseLinesError::ParseLine {
+        span: error.columns().map_or_else(|| line.full_span(), |columns| line.span(columns)),
+        error,
+    })
+}
+
+impl<'a, T: FromLine<'a>> BorrowedInput<'a> for Vec<T> {
+    type Error = ParseLinesError<T::Err>;
+
+    fn parse_str(input: &'a str) -> Result<Self, Self::Error> {
+        str_lines(input).map(|line| parse_str_line(&line)).collect()
+    }
+
+    fn error_span(error: &Self::Error) -> Option<Span> {
+        match error {
+            ParseLinesError::IoError(_) => None,
+            ParseLinesError::ParseLine { span, .. } => Some(span.clone()),
+        }
+    }
+}
+
+pub trait BorrowProblem {
+    type Input<'a>: BorrowedInput<'a>;
+    type Intermediate<'a>;
+    type Part1Output: Submission;
+    type Part2Output: Submission;
+    type Error: Error + 'static;
+
+    const PREPARES: bool = true;
+
+    fn prepare<'a>(input: &Self::Input<'a>) -> Result<Self::Intermediate<'a>, Self::Error>;
+    fn part_1<'a>(input: &Self::Input<'a>, intermediate: &Self::Intermediate<'a>) -> Result<Self::Part1Output, Self::Error>;
+    fn part_2<'a>(input: &Self::Input<'a>, intermediate: &Self::Intermediate<'a>) -> Result<Self::Part2Output, Self::Error>;
+}
+
+pub enum Contents {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+fn invalid_utf8(e: str::Utf8Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(feature = "mmap")]
+fn load_file(path: &str) -> io::Result<Contents> {
+    let file = fs::File::open(path)?;
+    // Safety: the map is only read, inputs aren't expected to change while a day runs.
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    str::from_utf8(&map).map_err(invalid_utf8)?;
+    Ok(Contents::Mapped(map))
+}
+
+#[cfg(not(feature = "mmap"))]
+fn load_file(path: &str) -> io::Result<Contents> {
+    fs::read_to_string(path).map(Contents::Owned)
+}
+
+impl Contents {
+    pub fn load(path: &str) -> io::Result<Self> {
+        if path == STDIN_PATH {
+            let contents = read_input(path)?;
+            String::from_utf8(contents).map(Contents::Owned).map_err(|e| invalid_utf8(e.utf8_error()))
+        } else {
+            load_file(path)
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Contents::Owned(contents) => contents,
+            // Safety: mapped contents are validated as UTF-8 when they're loaded.
+            #[cfg(feature = "mmap")]
+            Contents::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+fn solve_borrowed_into<P: BorrowProblem>(path: &str, options: SolveOptions, watch: &Watch, report: &mut SolveReport) -> Result<(), SolveError<ErasedError, ErasedError>> {
+    let contents = Contents::load(path)?;
+    let source = contents.as_str();
+    let plan = Plan::read(path, options)?;
+    let input = parse_measured(&plan, watch, source.as_bytes(), report, P::Input::error_span, || P::Input::parse_str(source).map(|input| (input, source.len())))?;
+    solve_parsed(&plan, watch, report, P::PREPARES, || P::prepare(&input), |intermediate| P::part_1(&input, intermediate), |intermediate| P::part_2(&input, intermediate))
+}
+
+pub fn solve_borrowed<P: BorrowProblem>(path: &str, options: SolveOptions) -> SolveReport {
+    solve_with(path, options, solve_borrowed_into::<P>)
+}
+
+pub fn run_borrowed<P: BorrowProblem>(dir: &str) {
+    run_with(dir, solve_borrowed::<P>);
+}
+
+pub fn bench_borrowed<P: BorrowProblem>(path: &str, options: &BenchOptions) -> Result<BenchReport, SolveError<ErasedError, P::Error>> {
+    let contents = Contents::load(path)?;
+    let source = contents.as_str();
+    bench_stages(
+        path,
+        options,
+        P::PREPARES,
+        || P::Input::parse_str(source).map_err(|e| ErasedError::new(&e)),
+        P::prepare,
+        P::part_1,
+        P::part_2,
+    )
+}
@@ -0,0 +1,60 @@
+use std::{
+    error::Error,
+    iter::FromIterator,
+    str::FromStr,
+};
+use crate::{FromGroup, Line, Span};
+
+pub use crate::groups::read_groups;
+pub use crate::span::lines;
+
+pub type FieldError = (Span, Box<dyn Error + Send + Sync>);
+
+pub fn from_str<T: FromStr>(s: &str) -> Result<T, T::Err> {
+    s.parse()
+}
+
+pub fn parse_line<T, E, F>(line: &Line, parse: F) -> Result<T, FieldError>
+where
+    E: Error + Send + Sync + 'static,
+    F: Fn(&str) -> Result<T, E>,
+{
+    parse(&line.text).map_err(|e| (line.full_span(), Box::new(e) as Box<dyn Error + Send + Sync>))
+}
+
+pub fn parse_split<C, T, E, F>(line: &Line, separator: &str, parse: F) -> Result<C, FieldError>
+where
+    C: FromIterator<T>,
+    E: Error + Send + Sync + 'static,
+    F: Fn(&str) -> Result<T, E>,
+{
+    let mut start = 0;
+    line.text.split(separator)
+        .map(|piece| {
+            let span = line.span(start..start + piece.len());
+            start += piece.len() + separator.len();
+            parse(piece).map_err(|e| (span, Box::new(e) as Box<dyn Error + Send + Sync>))
+        })
+        .collect()
+}
+
+pub fn parse_each_line<C, T, E, F>(lines: &[Line], parse: F) -> Result<C, FieldError>
+where
+    C: FromIterator<T>,
+    E: Error + Send + Sync + 'static,
+    F: Fn(&str) -> Result<T, E>,
+{
+    lines.iter().map(|line| parse_line(line, &parse)).collect()
+}
+
+pub fn parse_group<T: FromGroup>(lines: &[Line], section: &[Line]) -> Result<T, FieldError> {
+    T::from_group(lines).map_err(|e| (T::error_span(&e).unwrap_or_else(|| section[0].full_span()), Box::new(e) as Box<dyn Error + Send + Sync>))
+}
+
+pub fn parse_group_with<T, E, F>(lines: &[Line], section: &[Line], parse: F) -> Result<T, FieldError>
+where
+    E: Error + Send + Sync + 'static,
+    F: Fn(&[Line]) -> Result<T, E>,
+{
+    parse(lines).map_err(|e| (section[0].full_span(), Box::new(e) as Box<dyn Error + Send + Sync>))
+}
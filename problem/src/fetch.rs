@@ -0,0 +1,204 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+use crate::{INPUTS_DIR, REAL_INPUT, http::{self, HttpError, Response}};
+
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+pub const YEAR_ENV_VAR: &str = "AOC_YEAR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const DEFAULT_YEAR: u32 = 2020;
+
+const USER_AGENT: &str = "aoc-runner (problem crate)";
+
+const CHECKSUM_ALGORITHM: &str = "fnv1a64";
+
+#[derive(Debug)]
+pub enum FetchError {
+    IoError(io::Error),
+    HttpError(HttpError),
+    MissingSession,
+    InvalidYear(String),
+    Status {
+        status: u16,
+        body: String,
+    },
+    InvalidChecksum(PathBuf),
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: u64,
+        found: u64,
+    },
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::IoError(e)
+    }
+}
+
+impl From<HttpError> for FetchError {
+    fn from(e: HttpError) -> Self {
+        FetchError::HttpError(e)
+    }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::IoError(e) => write!(f, "{}", e),
+            FetchError::HttpError(e) => write!(f, "{}", e),
+            FetchError::MissingSession => write!(f, "no session token, set ${}", SESSION_ENV_VAR),
+            FetchError::InvalidYear(year) => write!(f, "invalid year `{}` in ${}", year, YEAR_ENV_VAR),
+            FetchError::Status { status, body } => write!(f, "server responded with {}: {}", status, body.trim()),
+            FetchError::InvalidChecksum(path) => write!(f, "{} isn't a valid checksum file", path.display()),
+            FetchError::ChecksumMismatch { path, expected, found } => {
+                write!(f, "{} has checksum {:016x} but {:016x} was recorded, delete it to download it again", path.display(), found, expected)
+            },
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::IoError(e) => Some(e),
+            FetchError::HttpError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub year: u32,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, year: u32) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = env::var(SESSION_ENV_VAR).map_err(|_| FetchError::MissingSession)?;
+        let base_url = env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let year = match env::var(YEAR_ENV_VAR) {
+            Ok(year) => year.parse().map_err(|_| FetchError::InvalidYear(year))?,
+            Err(_) => DEFAULT_YEAR,
+        };
+        Ok(Self::new(&base_url, session.trim(), year))
+    }
+
+    pub fn day_url(&self, day: usize, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, self.year, day, path)
+    }
+
+    pub fn send(&self, method: &str, url: &str, headers: &[(&str, &str)], body: &[u8]) -> Result<Response, FetchError> {
+        let cookie = format!("session={}", self.session);
+        let mut all_headers = vec![("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+        all_headers.extend_from_slice(headers);
+        Ok(http::send(method, url, &all_headers, body)?)
+    }
+
+    pub fn fetch_input(&self, day: usize) -> Result<Vec<u8>, FetchError> {
+        let response = self.send("GET", &self.day_url(day, "/input"), &[], &[])?;
+        if response.status != 200 {
+            return Err(FetchError::Status {
+                status: response.status,
+                body: response.text(),
+            });
+        }
+        Ok(response.body)
+    }
+}
+
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+pub fn checksum_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".checksum");
+    PathBuf::from(name)
+}
+
+fn read_checksum(path: &Path) -> Result<Option<u64>, FetchError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    contents
+        .trim()
+        .strip_prefix(CHECKSUM_ALGORITHM)
+        .and_then(|hash| u64::from_str_radix(hash.trim(), 16).ok())
+        .map(Some)
+        .ok_or_else(|| FetchError::InvalidChecksum(path.to_path_buf()))
+}
+
+fn write_checksum(path: &Path, checksum: u64) -> io::Result<()> {
+    fs::write(path, format!("{} {:016x}\n", CHECKSUM_ALGORITHM, checksum))
+}
+
+pub struct CachedInput {
+    pub path: PathBuf,
+    pub checksum: u64,
+    pub downloaded: bool,
+}
+
+// An input that is already on disk is never downloaded again. Its checksum is checked against the one
+// recorded when it was downloaded, or recorded now if it predates the cache.
+pub fn fetch_cached(client: &Client, day: usize, dir: &str) -> Result<CachedInput, FetchError> {
+    let inputs = Path::new(dir).join(INPUTS_DIR);
+    let path = inputs.join(REAL_INPUT);
+    let checksum_path = checksum_path(&path);
+
+    match fs::read(&path) {
+        Ok(contents) => {
+            let found = checksum(&contents);
+            match read_checksum(&checksum_path)? {
+                Some(expected) if expected != found => return Err(FetchError::ChecksumMismatch {
+                    path,
+                    expected,
+                    found,
+                }),
+                Some(_) => (),
+                None => write_checksum(&checksum_path, found)?,
+            }
+            return Ok(CachedInput {
+                path,
+                checksum: found,
+                downloaded: false,
+            });
+        },
+        Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+        Err(e) => return Err(e.into()),
+    }
+
+    let contents = client.fetch_input(day)?;
+    let checksum = checksum(&contents);
+    fs::create_dir_all(&inputs)?;
+    let partial = inputs.join(format!("{}.partial", REAL_INPUT));
+    fs::write(&partial, &contents)?;
+    fs::rename(&partial, &path)?;
+    write_checksum(&checksum_path, checksum)?;
+    Ok(CachedInput {
+        path,
+        checksum,
+        downloaded: true,
+    })
+}
@@ -0,0 +1,143 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+use grid::Grid;
+use crate::{FromGroup, Line, ProblemInput, Span, span};
+
+pub trait FromChar: Sized {
+    type Err: Error + Send + Sync + 'static;
+
+    fn from_char(c: char) -> Result<Self, Self::Err>;
+}
+
+#[derive(Debug)]
+pub struct InvalidCharError(pub char);
+
+impl Display for InvalidCharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid character `{}`", self.0)
+    }
+}
+
+impl Error for InvalidCharError {}
+
+impl FromChar for char {
+    type Err = Infallible;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        Ok(c)
+    }
+}
+
+impl FromChar for bool {
+    type Err = InvalidCharError;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            c => Err(InvalidCharError(c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseGridError<T> {
+    IoError(io::Error),
+    Empty,
+    InvalidChar {
+        span: Span,
+        error: T,
+    },
+    UnevenRow {
+        span: Span,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<T> From<io::Error> for ParseGridError<T> {
+    fn from(e: io::Error) -> Self {
+        ParseGridError::IoError(e)
+    }
+}
+
+impl<T> ParseGridError<T> {
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            ParseGridError::IoError(_) | ParseGridError::Empty => None,
+            ParseGridError::InvalidChar { span, .. } | ParseGridError::UnevenRow { span, .. } => Some(span),
+        }
+    }
+}
+
+impl<T: Display> Display for ParseGridError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::IoError(e) => write!(f, "failed to read line: {}", e),
+            ParseGridError::Empty => write!(f, "empty grid"),
+            ParseGridError::InvalidChar { span, error } => write!(f, "line {}, column {}: {}", span.line, span.column, error),
+            ParseGridError::UnevenRow { span, expected, found } => write!(f, "line {} has width {}, expected {}", span.line, found, expected),
+        }
+    }
+}
+
+impl<T: Error + 'static> Error for ParseGridError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseGridError::IoError(e) => Some(e),
+            ParseGridError::InvalidChar { error, .. } => Some(error),
+            ParseGridError::Empty | ParseGridError::UnevenRow { .. } => None,
+        }
+    }
+}
+
+fn parse_grid<T: FromChar>(lines: &[Line]) -> Result<Grid<T>, ParseGridError<T::Err>> {
+    let width = lines.first().ok_or(ParseGridError::Empty)?.text.chars().count();
+    let mut values = Vec::with_capacity(width * lines.len());
+    for line in lines {
+        let found = line.text.chars().count();
+        if found != width {
+            return Err(ParseGridError::UnevenRow {
+                span: line.full_span(),
+                expected: width,
+                found,
+            });
+        }
+        for (column, c) in line.text.char_indices() {
+            values.push(T::from_char(c).map_err(|error| ParseGridError::InvalidChar {
+                span: line.span(column..column + c.len_utf8()),
+                error,
+            })?);
+        }
+    }
+    Ok(Grid::from_vec(width, lines.len(), values))
+}
+
+impl<T: FromChar> ProblemInput for Grid<T> {
+    type Error = ParseGridError<T::Err>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error> {
+        let lines = span::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        parse_grid(&lines)
+    }
+
+    fn error_span(error: &Self::Error) -> Option<Span> {
+        error.span().cloned()
+    }
+}
+
+impl<T: FromChar> FromGroup for Grid<T> {
+    type Err = ParseGridError<T::Err>;
+
+    fn from_group(lines: &[Line]) -> Result<Self, Self::Err> {
+        parse_grid(lines)
+    }
+
+    fn error_span(error: &Self::Err) -> Option<Span> {
+        error.span().cloned()
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    str::FromStr,
+};
+use crate::{Line, LineError, ParseLinesError, ProblemInput, Span, parse_line, span};
+
+pub trait FromGroup: Sized {
+    type Err: Error + Send + Sync + 'static;
+
+    fn from_group(lines: &[Line]) -> Result<Self, Self::Err>;
+
+    fn error_span(_error: &Self::Err) -> Option<Span> {
+        None
+    }
+}
+
+impl<T: FromStr> FromGroup for Vec<T>
+where
+    T::Err: LineError,
+{
+    type Err = ParseLinesError<T::Err>;
+
+    fn from_group(lines: &[Line]) -> Result<Self, Self::Err> {
+        lines.iter().map(parse_line).collect()
+    }
+
+    fn error_span(error: &Self::Err) -> Option<Span> {
+        <Self as ProblemInput>::error_span(error)
+    }
+}
+
+pub struct Titled<T> {
+    pub title: String,
+    pub value: T,
+}
+
+#[derive(Debug)]
+pub enum ParseTitledError<E> {
+    ExpectedTitle,
+    ParseValue(E),
+}
+
+impl<E: Display> Display for ParseTitledError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTitledError::ExpectedTitle => write!(f, "expected a title line"),
+            ParseTitledError::ParseValue(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseTitledError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseTitledError::ExpectedTitle => None,
+            ParseTitledError::ParseValue(e) => Some(e),
+        }
+    }
+}
+
+impl<T: FromGroup> FromGroup for Titled<T> {
+    type Err = ParseTitledError<T::Err>;
+
+    fn from_group(lines: &[Line]) -> Result<Self, Self::Err> {
+        let (title, rest) = lines.split_first().ok_or(ParseTitledError::ExpectedTitle)?;
+        Ok(Self {
+            title: title.text.clone(),
+            value: T::from_group(rest).map_err(ParseTitledError::ParseValue)?,
+        })
+    }
+
+    fn error_span(error: &Self::Err) -> Option<Span> {
+        match error {
+            ParseTitledError::ExpectedTitle => None,
+            ParseTitledError::ParseValue(e) => T::error_span(e),
+        }
+    }
+}
+
+pub fn read_groups<R: BufRead>(reader: R) -> io::Result<Vec<Vec<Line>>> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    for line in span::lines(reader) {
+        let line = line?;
+        if line.text.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = Vec::new();
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+fn group_span<T: FromGroup>(group: &[Line], error: &T::Err) -> Span {
+    T::error_span(error).unwrap_or_else(|| group[0].full_span())
+}
+
+pub struct Groups<T>(pub Vec<T>);
+
+#[derive(Debug)]
+pub enum ParseGroupsError<T> {
+    IoError(io::Error),
+    ParseGroup {
+        group_number: usize,
+        span: Span,
+        error: T,
+    },
+}
+
+impl<T> From<io::Error> for ParseGroupsError<T> {
+    fn from(e: io::Error) -> Self {
+        ParseGroupsError::IoError(e)
+    }
+}
+
+impl<T: Display> Display for ParseGroupsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGroupsError::IoError(e) => write!(f, "failed to read line: {}", e),
+            // The group's error already says where in the group it is.
+            ParseGroupsError::ParseGroup { group_number, error, .. } => write!(f, "group {}: {}", group_number, error),
+        }
+    }
+}
+
+impl<T: Error + 'static> Error for ParseGroupsError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseGroupsError::IoError(e) => Some(e),
+            ParseGroupsError::ParseGroup { error, .. } => Some(error),
+        }
+    }
+}
+
+impl<T: FromGroup> ProblemInput for Groups<T> {
+    type Error = ParseGroupsError<T::Err>;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error> {
+        read_groups(reader)?
+            .iter()
+            .enumerate()
+            .map(|(i, group)| T::from_group(group).map_err(|error| ParseGroupsError::ParseGroup {
+                group_number: i + 1,
+                span: group_span::<T>(group, &error),
+                error,
+            }))
+            .collect::<Result<_, _>>()
+            .map(Groups)
+    }
+
+    fn error_span(error: &Self::Error) -> Option<Span> {
+        match error {
+            ParseGroupsError::IoError(_) => None,
+            ParseGroupsError::ParseGroup { span, .. } => Some(span.clone()),
+        }
+    }
+}
+
+pub struct Sections<T>(pub T);
+
+#[derive(Debug)]
+pub enum ParseSectionsError {
+    IoError(io::Error),
+    MissingSection(usize),
+    UnexpectedSection {
+        section_number: usize,
+        span: Span,
+    },
+    ParseSection {
+        section_number: usize,
+        span: Span,
+        error: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl From<io::Error> for ParseSectionsError {
+    fn from(e: io::Error) -> Self {
+        ParseSectionsError::IoError(e)
+    }
+}
+
+impl ParseSectionsError {
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            ParseSectionsError::IoError(_) | ParseSectionsError::MissingSection(_) => None,
+            ParseSectionsError::UnexpectedSection { span, .. } | ParseSectionsError::ParseSection { span, .. } => Some(span),
+        }
+    }
+}
+
+impl Display for ParseSectionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSectionsError::IoError(e) => write!(f, "failed to read line: {}", e),
+            ParseSectionsError::MissingSection(section_number) => write!(f, "missing section {}", section_number),
+            ParseSectionsError::UnexpectedSection { section_number, span } => write!(f, "unexpected section {} at line {}", section_number, span.line),
+            ParseSectionsError::ParseSection { section_number, error, .. } => write!(f, "section {}: {}", section_number, error),
+        }
+    }
+}
+
+impl Error for ParseSectionsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseSectionsError::IoError(e) => Some(e),
+            ParseSectionsError::ParseSection { error, .. } => Some(error.as_ref()),
+            ParseSectionsError::MissingSection(_) | ParseSectionsError::UnexpectedSection { .. } => None,
+        }
+    }
+}
+
+fn parse_section<T: FromGroup>(groups: &[Vec<Line>], index: usize) -> Result<T, ParseSectionsError> {
+    let group = groups.get(index).ok_or(ParseSectionsError::MissingSection(index + 1))?;
+    T::from_group(group).map_err(|error| ParseSectionsError::ParseSection {
+        section_number: index + 1,
+        span: group_span::<T>(group, &error),
+        error: Box::new(error),
+    })
+}
+
+macro_rules! impl_sections {
+    ($($section:ident $index:tt),+) => {
+        impl<$($section: FromGroup),+> ProblemInput for Sections<($($section,)+)> {
+            type Error = ParseSectionsError;
+
+            fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error> {
+                let groups = read_groups(reader)?;
+                let count = [$($index),+].len();
+                if let Some(extra) = groups.get(count) {
+                    return Err(ParseSectionsError::UnexpectedSection {
+                        section_number: count + 1,
+                        span: extra[0].full_span(),
+                    });
+                }
+                Ok(Sections(($(parse_section::<$section>(&groups, $index)?,)+)))
+            }
+
+            fn error_span(error: &Self::Error) -> Option<Span> {
+                error.span().cloned()
+            }
+        }
+    };
+}
+
+impl_sections!(A 0, B 1);
+impl_sections!(A 0, B 1, C 2);
+impl_sections!(A 0, B 1, C 2, D 3);
@@ -0,0 +1,90 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Read},
+    time::Duration,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum HttpError {
+    IoError(io::Error),
+    Transport(Box<ureq::Transport>),
+}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::IoError(e)
+    }
+}
+
+impl From<ureq::Transport> for HttpError {
+    fn from(e: ureq::Transport) -> Self {
+        HttpError::Transport(Box::new(e))
+    }
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::IoError(e) => write!(f, "failed to read response: {}", e),
+            HttpError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl Error for HttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HttpError::IoError(e) => Some(e),
+            HttpError::Transport(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    fn read(response: ureq::Response) -> Result<Self, HttpError> {
+        let status = response.status();
+        let headers = response
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| response.header(&name).map(|value| (name.clone(), value.to_string())))
+            .collect();
+        let mut body = Vec::new();
+        response.into_reader().read_to_end(&mut body)?;
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+// Error statuses are returned as responses, the callers decide what they mean. Redirects aren't followed
+// so a login page isn't mistaken for an input. Proxies are picked up from $HTTPS_PROXY and friends.
+pub fn send(method: &str, url: &str, headers: &[(&str, &str)], body: &[u8]) -> Result<Response, HttpError> {
+    let agent = ureq::AgentBuilder::new().timeout(TIMEOUT).redirects(0).try_proxy_from_env(true).build();
+    let mut request = agent.request(method, url);
+    for (key, value) in headers {
+        request = request.set(key, value);
+    }
+    match request.send_bytes(body) {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => Response::read(response),
+        Err(ureq::Error::Transport(e)) => Err(e.into()),
+    }
+}
@@ -1,22 +1,61 @@
 use std::{
-    fmt::{Debug, Display},
-    fs::File,
-    io::{self, BufRead, BufReader},
+    env,
+    error::Error,
+    fmt::{self, Debug, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+    process,
     str::FromStr,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
+pub mod bench;
+pub mod borrowed;
+#[doc(hidden)]
+pub mod derive;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod grid;
+pub mod groups;
+#[cfg(feature = "fetch")]
+pub mod http;
+mod json;
+pub mod memory;
+pub mod pattern;
+pub mod report;
+pub mod span;
+pub mod stream;
+#[cfg(feature = "fetch")]
+pub mod submit;
+mod timeout;
+
+pub use problem_derive::ProblemInput;
+pub use borrowed::{BorrowProblem, BorrowedInput, FromLine, StrLine, bench_borrowed, run_borrowed, solve_borrowed};
+pub use grid::{FromChar, InvalidCharError, ParseGridError};
+pub use groups::{FromGroup, Groups, ParseGroupsError, ParseSectionsError, ParseTitledError, Sections, Titled};
+pub use memory::{CountingAllocator, MemoryStats};
+pub use pattern::PatternError;
+pub use report::{JsonReporter, Reporter, TextReporter, TsvReporter};
+pub use span::{Line, LineError, Snippet, Span};
+pub use stream::{RecordSource, StreamProblem, Streamed, run_stream, solve_stream};
+use timeout::{Watch, solve_with};
+
 pub trait ProblemInput: Sized {
-    type Error: Debug;
+    type Error: Error;
 
     fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error>;
+
+    fn error_span(_error: &Self::Error) -> Option<Span> {
+        None
+    }
 }
 
 #[derive(Debug)]
 pub enum ParseLinesError<T> {
     IoError(io::Error),
     ParseLine {
-        line_number: usize,
+        span: Span,
         error: T,
     },
 }
@@ -27,19 +66,51 @@ impl<T> From<io::Error> for ParseLinesError<T> {
     }
 }
 
+impl<T: Display> Display for ParseLinesError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLinesError::IoError(e) => write!(f, "failed to read line: {}", e),
+            ParseLinesError::ParseLine { span, error } => write!(f, "line {}, column {}: {}", span.line, span.column, error),
+        }
+    }
+}
+
+impl<T: Error + 'static> Error for ParseLinesError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseLinesError::IoError(e) => Some(e),
+            ParseLinesError::ParseLine { error, .. } => Some(error),
+        }
+    }
+}
+
+fn parse_line<T: FromStr>(line: &Line) -> Result<T, ParseLinesError<T::Err>>
+where
+    T::Err: LineError,
+{
+    line.text.parse().map_err(|error: T::Err| ParseLinesError::ParseLine {
+        span: error.columns().map_or_else(|| line.full_span(), |columns| line.span(columns)),
+        error,
+    })
+}
+
 impl<T: FromStr> ProblemInput for Vec<T>
 where
-    T::Err: Debug,
+    T::Err: LineError,
 {
     type Error = ParseLinesError<T::Err>;
 
     fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error> {
-        Ok(
-            reader.lines()
-                .enumerate()
-                .map(|(line_number, line)| line?.parse().map_err(|error| ParseLinesError::ParseLine { line_number: line_number + 1, error }))
-                .collect::<Result<Vec<_>, _>>()?
-        )
+        span::lines(reader)
+            .map(|line| parse_line(&line?))
+            .collect()
+    }
+
+    fn error_span(error: &Self::Error) -> Option<Span> {
+        match error {
+            ParseLinesError::IoError(_) => None,
+            ParseLinesError::ParseLine { span, .. } => Some(span.clone()),
+        }
     }
 }
 
@@ -58,14 +129,34 @@ impl<T> From<io::Error> for OneError<T> {
     }
 }
 
+impl<T: Display> Display for OneError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OneError::IoError(e) => write!(f, "failed to read line: {}", e),
+            OneError::NoInput => write!(f, "input is empty"),
+            OneError::ParseError(e) => write!(f, "line 1: {}", e),
+        }
+    }
+}
+
+impl<T: Error + 'static> Error for OneError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OneError::IoError(e) => Some(e),
+            OneError::NoInput => None,
+            OneError::ParseError(e) => Some(e),
+        }
+    }
+}
+
 impl<T: FromStr> ProblemInput for One<T>
 where
-    T::Err: Debug,
+    T::Err: Error + 'static,
 {
     type Error = OneError<T::Err>;
 
     fn parse<R: BufRead>(reader: R) -> Result<Self, Self::Error> {
-        Ok(One(reader.lines().next().ok_or(OneError::NoInput)??.parse().map_err(|e| OneError::ParseError(e))?))
+        Ok(One(reader.lines().next().ok_or(OneError::NoInput)??.parse().map_err(OneError::ParseError)?))
     }
 }
 
@@ -83,45 +174,640 @@ impl<T: FromStr> FromStr for CSV<T> {
     }
 }
 
+// Returned by parts that search for an answer and don't find one.
+#[derive(Debug)]
+pub struct NoSolution;
+
+impl Display for NoSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no solution found")
+    }
+}
+
+impl Error for NoSolution {}
+
+// What `aoc submit` sends for an answer. Most answers are sent as they're displayed, outputs that show more
+// than the answer or that aren't an answer at all override it.
+pub trait Submission: Display {
+    fn submission(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+macro_rules! impl_submission {
+    ($($ty:ty),*) => {
+        $(impl Submission for $ty {})*
+    };
+}
+
+impl_submission!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String);
+
 pub trait Problem {
     type Input: ProblemInput;
-    type Part1Output: Display;
-    type Part2Output: Display;
-    type Error;
+    type Part1Output: Submission;
+    type Part2Output: Submission;
+    type Error: Error + 'static;
 
     fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error>;
     fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error>;
 }
 
+pub trait PreparedProblem {
+    type Input: ProblemInput;
+    type Intermediate;
+    type Part1Output: Submission;
+    type Part2Output: Submission;
+    type Error: Error + 'static;
+
+    // Whether `prepare` does any work worth timing and reporting.
+    const PREPARES: bool = true;
+
+    fn prepare(input: &Self::Input) -> Result<Self::Intermediate, Self::Error>;
+    fn part_1(input: &Self::Input, intermediate: &Self::Intermediate) -> Result<Self::Part1Output, Self::Error>;
+    fn part_2(input: &Self::Input, intermediate: &Self::Intermediate) -> Result<Self::Part2Output, Self::Error>;
+}
+
+impl<P: Problem> PreparedProblem for P {
+    type Input = P::Input;
+    type Intermediate = ();
+    type Part1Output = P::Part1Output;
+    type Part2Output = P::Part2Output;
+    type Error = P::Error;
+
+    const PREPARES: bool = false;
+
+    fn prepare(_input: &Self::Input) -> Result<Self::Intermediate, Self::Error> {
+        Ok(())
+    }
+
+    fn part_1(input: &Self::Input, _intermediate: &Self::Intermediate) -> Result<Self::Part1Output, Self::Error> {
+        P::part_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _intermediate: &Self::Intermediate) -> Result<Self::Part2Output, Self::Error> {
+        P::part_2(input)
+    }
+}
+
 #[derive(Debug)]
 pub enum SolveError<P, E> {
     IoError(io::Error),
     ParseInput(P),
+    Prepare(E),
     SolvePart1(E),
     SolvePart2(E),
 }
 
+impl<P, E> SolveError<P, E> {
+    pub fn phase(&self) -> &'static str {
+        match self {
+            Self::IoError(_) => "io",
+            Self::ParseInput(_) => "parse",
+            Self::Prepare(_) => "prepare",
+            Self::SolvePart1(_) => "part_1",
+            Self::SolvePart2(_) => "part_2",
+        }
+    }
+}
+
+impl<P: Error, E: Error> SolveError<P, E> {
+    pub fn erase(self) -> SolveError<ErasedError, ErasedError> {
+        match self {
+            Self::IoError(e) => SolveError::IoError(e),
+            Self::ParseInput(e) => SolveError::ParseInput(ErasedError::new(&e)),
+            Self::Prepare(e) => SolveError::Prepare(ErasedError::new(&e)),
+            Self::SolvePart1(e) => SolveError::SolvePart1(ErasedError::new(&e)),
+            Self::SolvePart2(e) => SolveError::SolvePart2(ErasedError::new(&e)),
+        }
+    }
+}
+
+impl<P: Display, E: Display> Display for SolveError<P, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(e) => write!(f, "failed to read input: {}", e),
+            Self::ParseInput(e) => write!(f, "failed to parse input: {}", e),
+            Self::Prepare(e) => write!(f, "prepare failed: {}", e),
+            Self::SolvePart1(e) => write!(f, "part 1 failed: {}", e),
+            Self::SolvePart2(e) => write!(f, "part 2 failed: {}", e),
+        }
+    }
+}
+
+impl<P: Error + 'static, E: Error + 'static> Error for SolveError<P, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(e) => Some(e),
+            Self::ParseInput(e) => Some(e),
+            Self::Prepare(e) | Self::SolvePart1(e) | Self::SolvePart2(e) => Some(e),
+        }
+    }
+}
+
 impl<P, E> From<io::Error> for SolveError<P, E> {
     fn from(e: io::Error) -> Self {
         Self::IoError(e)
     }
 }
 
-pub fn solve<P: Problem>(path: &str) -> Result<(P::Part1Output, P::Part2Output), SolveError<<P::Input as ProblemInput>::Error, P::Error>> {
-    let input_file = BufReader::new(File::open(path)?);
-    let input = P::Input::parse(input_file).map_err(|e| SolveError::ParseInput(e))?;
+// Keeps the messages of an error and its sources after its type is erased, so reports can be sent between threads.
+#[derive(Clone, Debug)]
+pub struct ErasedError {
+    message: String,
+    source: Option<Box<ErasedError>>,
+}
 
-    let start = Instant::now();
-    let part_1 = P::part_1(&input).map_err(|e| SolveError::SolvePart1(e))?;
-    let duration = Instant::now().duration_since(start);
+impl ErasedError {
+    pub fn new(error: &dyn Error) -> Self {
+        Self {
+            message: error.to_string(),
+            source: error.source().map(|source| Box::new(Self::new(source))),
+        }
+    }
+}
 
-    println!("Part 1:\n  Solution: {}\n  Elapsed:  {} seconds", part_1, duration.as_secs_f64());
+impl Display for ErasedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ErasedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    Unknown,
+}
+
+impl Verdict {
+    fn check(value: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) => {
+                if value == expected {
+                    Verdict::Pass
+                } else {
+                    Verdict::Fail {
+                        expected: expected.to_string(),
+                    }
+                }
+            },
+            None => Verdict::Unknown,
+        }
+    }
 
-    let start = Instant::now();
-    let part_2 = P::part_2(&input).map_err(|e| SolveError::SolvePart2(e))?;
-    let duration = Instant::now().duration_since(start);
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
 
-    println!("Part 2:\n  Solution: {}\n  Elapsed:  {} seconds", part_2, duration.as_secs_f64());
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub value: String,
+    // None for outputs there's nothing to submit for.
+    pub submission: Option<String>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+    pub memory: Option<MemoryStats>,
+}
+
+impl Answer {
+    fn new<T: Submission>(output: T, elapsed: Duration, memory: Option<MemoryStats>, expected: Option<&str>) -> Self {
+        let value = output.to_string();
+        Self {
+            verdict: Verdict::check(&value, expected),
+            value,
+            submission: output.submission(),
+            elapsed,
+            memory,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PartOutcome {
+    Solved(Answer),
+    Skipped,
+    TimedOut(Duration),
+}
+
+impl PartOutcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            PartOutcome::Solved(answer) => Some(answer),
+            PartOutcome::Skipped | PartOutcome::TimedOut(_) => None,
+        }
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self, PartOutcome::TimedOut(_))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Parts {
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    pub fn includes(&self, part: usize) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Part1 => part == 1,
+            Parts::Part2 => part == 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsePartsError(pub String);
+
+impl Display for ParsePartsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid part `{}`, expected 1, 2 or both", self.0)
+    }
+}
 
-    Ok((part_1, part_2))
+impl Error for ParsePartsError {}
+
+impl FromStr for Parts {
+    type Err = ParsePartsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => Parts::Both,
+            "1" | "part_1" => Parts::Part1,
+            "2" | "part_2" => Parts::Part2,
+            s => return Err(ParsePartsError(s.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SolveOptions {
+    pub parts: Parts,
+    pub timeout: Option<Duration>,
+    // Only has an effect when the `memory` feature installs the counting allocator.
+    pub memory: bool,
+}
+
+impl Default for SolveOptions {
+    fn default() -> Self {
+        Self {
+            parts: Parts::Both,
+            timeout: None,
+            memory: false,
+        }
+    }
+}
+
+pub const STDIN_PATH: &str = "-";
+
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+pub const INPUTS_DIR: &str = "inputs";
+
+pub const REAL_INPUT: &str = "real.txt";
+
+pub fn discover_inputs(dir: &str) -> io::Result<Vec<String>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(Path::new(dir).join(INPUTS_DIR))? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
+            paths.push(path.to_string_lossy().into_owned());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+pub fn input_name(path: &str) -> &str {
+    Path::new(path).file_stem().and_then(|name| name.to_str()).unwrap_or(path)
+}
+
+fn read_input(path: &str) -> io::Result<Vec<u8>> {
+    if path == STDIN_PATH {
+        let mut contents = Vec::new();
+        io::stdin().lock().read_to_end(&mut contents)?;
+        Ok(contents)
+    } else {
+        fs::read(path)
+    }
+}
+
+// A `-` line in an answers file marks a part that doesn't apply to the input, like an example that only
+// covers the other part. That part is skipped instead of solved.
+const NOT_APPLICABLE: &str = "-";
+
+fn read_answers(path: &str) -> io::Result<Vec<Option<String>>> {
+    if path == STDIN_PATH {
+        return Ok(Vec::new());
+    }
+
+    match File::open(format!("{}.answers", path)) {
+        Ok(file) => BufReader::new(file)
+            .lines()
+            .map(|line| line.map(|l| if l.is_empty() { None } else { Some(l) }))
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+// Which parts of an input run and the answers they're checked against.
+struct Plan {
+    options: SolveOptions,
+    answers: Vec<Option<String>>,
+}
+
+impl Plan {
+    fn read(path: &str, options: SolveOptions) -> io::Result<Self> {
+        Ok(Self {
+            options,
+            answers: read_answers(path)?,
+        })
+    }
+
+    fn expected(&self, part: usize) -> Option<&str> {
+        self.answers.get(part - 1).and_then(|a| a.as_deref())
+    }
+
+    fn runs(&self, part: usize) -> bool {
+        self.options.parts.includes(part) && self.expected(part) != Some(NOT_APPLICABLE)
+    }
+
+    // Parsing and preparing only happen for the parts that run, so their time counts toward the first of them.
+    fn first_part(&self) -> usize {
+        if self.runs(1) { 1 } else { 2 }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ParseInfo {
+    pub bytes: usize,
+    pub lines: usize,
+    pub elapsed: Duration,
+    pub memory: Option<MemoryStats>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PrepareInfo {
+    pub elapsed: Duration,
+    pub memory: Option<MemoryStats>,
+}
+
+#[derive(Debug)]
+pub struct SolveReport {
+    pub path: String,
+    pub parse: Option<ParseInfo>,
+    pub prepare: Option<PrepareInfo>,
+    pub part_1: Option<PartOutcome>,
+    pub part_2: Option<PartOutcome>,
+    pub error: Option<SolveError<ErasedError, ErasedError>>,
+    pub snippet: Option<Snippet>,
+}
+
+impl SolveReport {
+    fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            parse: None,
+            prepare: None,
+            part_1: None,
+            part_2: None,
+            error: None,
+            snippet: None,
+        }
+    }
+
+    // Everything recorded so far, taken before a part starts.
+    fn progress(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            parse: self.parse.clone(),
+            prepare: self.prepare.clone(),
+            part_1: self.part_1.clone(),
+            part_2: self.part_2.clone(),
+            error: None,
+            snippet: None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && !self.is_timed_out() && self.answers().all(|answer| !answer.verdict.is_fail())
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        self.part_1.iter().chain(self.part_2.iter()).any(PartOutcome::is_timed_out)
+    }
+
+    pub fn answers(&self) -> impl Iterator<Item = &Answer> {
+        self.part_1.iter().chain(self.part_2.iter()).filter_map(PartOutcome::answer)
+    }
+}
+
+fn count_lines(bytes: &[u8]) -> usize {
+    let mut lines = bytes.iter().filter(|&&b| b == b'\n').count();
+    if !bytes.is_empty() && !bytes.ends_with(b"\n") {
+        lines += 1;
+    }
+    lines
+}
+
+fn measured<R>(memory: bool, f: impl FnOnce() -> R) -> (R, Duration, Option<MemoryStats>) {
+    let ((result, duration), memory) = memory::measure(memory, || {
+        let start = Instant::now();
+        let result = f();
+        (result, Instant::now().duration_since(start))
+    });
+    (result, duration, memory)
+}
+
+fn solve_part<T: Submission, E: Error>(
+    included: bool,
+    memory: bool,
+    expected: Option<&str>,
+    error: fn(ErasedError) -> SolveError<ErasedError, ErasedError>,
+    solve: impl FnOnce() -> Result<T, E>,
+) -> Result<PartOutcome, SolveError<ErasedError, ErasedError>> {
+    if !included {
+        return Ok(PartOutcome::Skipped);
+    }
+
+    let (answer, duration, memory) = measured(memory, solve);
+    let answer = answer.map_err(|e| error(ErasedError::new(&e)))?;
+    Ok(PartOutcome::Solved(Answer::new(answer, duration, memory, expected)))
+}
+
+// Times the parse step and records how much of the input it read, `parse` returns the input and the number of
+// bytes it consumed.
+fn parse_measured<T, E: Error>(
+    plan: &Plan,
+    watch: &Watch,
+    source: &[u8],
+    report: &mut SolveReport,
+    error_span: fn(&E) -> Option<Span>,
+    parse: impl FnOnce() -> Result<(T, usize), E>,
+) -> Result<T, SolveError<ErasedError, ErasedError>> {
+    watch.start(plan.first_part(), report);
+    let (parsed, duration, memory) = measured(plan.options.memory, parse);
+    let (input, consumed) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            report.snippet = error_span(&e).map(|span| Snippet::new(source, span));
+            return Err(SolveError::ParseInput(ErasedError::new(&e)));
+        },
+    };
+
+    report.parse = Some(ParseInfo {
+        bytes: consumed,
+        lines: count_lines(&source[..consumed]),
+        elapsed: duration,
+        memory,
+    });
+    Ok(input)
+}
+
+// Prepares and solves the parts of a parsed input. A worker the runner gave up on stops at the next stage
+// instead of running it for nothing.
+fn solve_parsed<I, T1: Submission, T2: Submission, E: Error>(
+    plan: &Plan,
+    watch: &Watch,
+    report: &mut SolveReport,
+    prepares: bool,
+    prepare: impl FnOnce() -> Result<I, E>,
+    part_1: impl FnOnce(&I) -> Result<T1, E>,
+    part_2: impl FnOnce(&I) -> Result<T2, E>,
+) -> Result<(), SolveError<ErasedError, ErasedError>> {
+    if watch.is_cancelled() {
+        return Ok(());
+    }
+    let (intermediate, duration, memory) = measured(plan.options.memory, prepare);
+    let intermediate = intermediate.map_err(|e| SolveError::Prepare(ErasedError::new(&e)))?;
+    if prepares {
+        report.prepare = Some(PrepareInfo {
+            elapsed: duration,
+            memory,
+        });
+    }
+
+    let memory = plan.options.memory;
+    report.part_1 = Some(solve_part(plan.runs(1) && !watch.is_cancelled(), memory, plan.expected(1), SolveError::SolvePart1, || part_1(&intermediate))?);
+    if plan.runs(1) {
+        watch.start(2, report);
+    }
+    report.part_2 = Some(solve_part(plan.runs(2) && !watch.is_cancelled(), memory, plan.expected(2), SolveError::SolvePart2, || part_2(&intermediate))?);
+
+    Ok(())
+}
+
+fn solve_into<P: PreparedProblem>(path: &str, options: SolveOptions, watch: &Watch, report: &mut SolveReport) -> Result<(), SolveError<ErasedError, ErasedError>> {
+    let contents = read_input(path)?;
+    let plan = Plan::read(path, options)?;
+    let input = parse_measured(&plan, watch, &contents, report, P::Input::error_span, || {
+        let mut reader = contents.as_slice();
+        P::Input::parse(&mut reader).map(|input| (input, contents.len() - reader.len()))
+    })?;
+    solve_parsed(&plan, watch, report, P::PREPARES, || P::prepare(&input), |intermediate| P::part_1(&input, intermediate), |intermediate| P::part_2(&input, intermediate))
+}
+
+pub fn solve<P: PreparedProblem>(path: &str, options: SolveOptions) -> SolveReport {
+    solve_with(path, options, solve_into::<P>)
+}
+
+struct Args {
+    options: SolveOptions,
+    input: Option<String>,
+}
+
+pub fn parse_timeout(s: &str) -> Option<Duration> {
+    s.parse().ok().filter(|&seconds: &f64| seconds.is_finite() && seconds > 0.0).map(Duration::from_secs_f64)
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Option<Args> {
+    let mut options = SolveOptions::default();
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => options.parts = args.next()?.parse().ok()?,
+            "--timeout" => options.timeout = Some(parse_timeout(&args.next()?)?),
+            "--memory" => options.memory = true,
+            _ if arg.starts_with("--") || input.is_some() => return None,
+            _ => input = Some(arg),
+        }
+    }
+    Some(Args {
+        options,
+        input,
+    })
+}
+
+pub fn run<P: PreparedProblem>(dir: &str) {
+    run_with(dir, solve::<P>);
+}
+
+fn run_with(dir: &str, solve: fn(&str, SolveOptions) -> SolveReport) {
+    let mut args = env::args();
+    let program = args.next().unwrap_or_default();
+    let args = match parse_args(args) {
+        Some(args) => args,
+        None => {
+            eprintln!("Usage: {} [--part 1|2|both] [--timeout SECONDS] [--memory] [INPUT]\n\nINPUT is a path or `{}` for stdin. Defaults to ${} if set, otherwise every *.txt file in {}/{}.", program, STDIN_PATH, INPUT_ENV_VAR, dir, INPUTS_DIR);
+            process::exit(2);
+        },
+    };
+    if args.options.memory && !memory::is_installed() {
+        eprintln!("{} was built without the `memory` feature, `--memory` has no effect", program);
+    }
+
+    let paths = match args.input.or_else(|| env::var(INPUT_ENV_VAR).ok()) {
+        Some(path) => vec![path],
+        None => match discover_inputs(dir) {
+            Ok(paths) if !paths.is_empty() => paths,
+            Ok(_) => {
+                eprintln!("No inputs found in {}/{}", dir, INPUTS_DIR);
+                process::exit(1);
+            },
+            Err(e) => {
+                eprintln!("Failed to read {}/{}: {}", dir, INPUTS_DIR, e);
+                process::exit(1);
+            },
+        },
+    };
+
+    let mut reporter = TextReporter::new(io::stdout());
+    let mut success = true;
+    for (i, path) in paths.iter().enumerate() {
+        if paths.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("Input {}:", input_name(path));
+        }
+        let report = solve(path, args.options);
+        reporter.report(&report).unwrap();
+        success &= report.is_success();
+    }
+    if !success {
+        process::exit(1);
+    }
 }
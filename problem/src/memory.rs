@@ -0,0 +1,93 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryStats {
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+// Counts are kept per thread so inputs solved concurrently don't see each other's allocations.
+thread_local! {
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record(allocated: isize, freed: isize) {
+    let _ = TRACKING.try_with(|tracking| {
+        if tracking.get() {
+            let live = LIVE.get() + allocated - freed;
+            LIVE.set(live);
+            PEAK.set(PEAK.get().max(live));
+            if allocated > 0 {
+                ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+            }
+        }
+    });
+}
+
+// Reallocations count as allocations. Memory freed during a phase that was allocated before it
+// lowers the live total, so the peak is relative to what was live when the phase started.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size() as isize);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize, layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+pub fn is_installed() -> bool {
+    cfg!(feature = "memory")
+}
+
+pub(crate) fn measure<R>(enabled: bool, f: impl FnOnce() -> R) -> (R, Option<MemoryStats>) {
+    if !enabled || !is_installed() {
+        return (f(), None);
+    }
+
+    LIVE.set(0);
+    PEAK.set(0);
+    ALLOCATIONS.set(0);
+    let previous = TRACKING.replace(true);
+    let result = f();
+    TRACKING.set(previous);
+
+    let stats = MemoryStats {
+        peak_bytes: PEAK.get() as usize,
+        allocations: ALLOCATIONS.get(),
+    };
+    (result, Some(stats))
+}
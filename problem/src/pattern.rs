@@ -0,0 +1,153 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+use crate::LineError;
+
+#[macro_export]
+macro_rules! parse_pattern {
+    ($pattern:expr, $s:expr) => {
+        $crate::pattern::parse($pattern, $s)
+    };
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    ExpectedLiteral {
+        literal: String,
+        position: usize,
+    },
+    InvalidPlaceholder {
+        placeholder: usize,
+        value: String,
+        columns: Range<usize>,
+        error: Box<dyn Error + Send + Sync>,
+    },
+    PlaceholderCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::ExpectedLiteral { literal, position } => write!(f, "expected `{}` at column {}", literal, position + 1),
+            PatternError::InvalidPlaceholder { placeholder, value, error, .. } => write!(f, "invalid value `{}` for placeholder {}: {}", value, placeholder, error),
+            PatternError::PlaceholderCount { expected, found } => write!(f, "pattern has {} placeholders but {} values were requested", found, expected),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatternError::InvalidPlaceholder { error, .. } => Some(error.as_ref()),
+            PatternError::ExpectedLiteral { .. } | PatternError::PlaceholderCount { .. } => None,
+        }
+    }
+}
+
+impl LineError for PatternError {
+    fn columns(&self) -> Option<Range<usize>> {
+        match self {
+            PatternError::ExpectedLiteral { position, .. } => Some(*position..*position + 1),
+            PatternError::InvalidPlaceholder { columns, .. } => Some(columns.clone()),
+            PatternError::PlaceholderCount { .. } => None,
+        }
+    }
+}
+
+pub struct Capture<'a> {
+    pub text: &'a str,
+    pub start: usize,
+}
+
+pub fn captures<'a>(pattern: &str, s: &'a str) -> Result<Vec<Capture<'a>>, PatternError> {
+    let expected = |literal: &str, position| PatternError::ExpectedLiteral {
+        literal: literal.to_string(),
+        position,
+    };
+
+    let mut literals = pattern.split("{}");
+    let first = literals.next().unwrap();
+    if !s.starts_with(first) {
+        return Err(expected(first, 0));
+    }
+
+    let literals = literals.collect::<Vec<_>>();
+    let mut position = first.len();
+    let mut captures = Vec::with_capacity(literals.len());
+    for (i, literal) in literals.iter().enumerate() {
+        let end = if i + 1 == literals.len() {
+            if !s[position..].ends_with(literal) {
+                return Err(expected(literal, s.len()));
+            }
+            s.len() - literal.len()
+        } else {
+            position + s[position..].find(literal).ok_or_else(|| expected(literal, position))?
+        };
+        captures.push(Capture {
+            text: &s[position..end],
+            start: position,
+        });
+        position = end + literal.len();
+    }
+
+    if captures.is_empty() && s.len() != first.len() {
+        return Err(expected("", first.len()));
+    }
+
+    Ok(captures)
+}
+
+fn parse_capture<T: FromStr>(captures: &[Capture<'_>], index: usize) -> Result<T, PatternError>
+where
+    T::Err: Error + Send + Sync + 'static,
+{
+    let capture = &captures[index];
+    capture.text.parse().map_err(|e| PatternError::InvalidPlaceholder {
+        placeholder: index + 1,
+        value: capture.text.to_string(),
+        columns: capture.start..capture.start + capture.text.len(),
+        error: Box::new(e),
+    })
+}
+
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &[Capture<'_>]) -> Result<Self, PatternError>;
+}
+
+macro_rules! impl_from_captures {
+    ($count:literal: $($value:ident $index:tt),+) => {
+        impl<$($value: FromStr),+> FromCaptures for ($($value,)+)
+        where
+            $($value::Err: Error + Send + Sync + 'static),+
+        {
+            fn from_captures(captures: &[Capture<'_>]) -> Result<Self, PatternError> {
+                if captures.len() != $count {
+                    return Err(PatternError::PlaceholderCount {
+                        expected: $count,
+                        found: captures.len(),
+                    });
+                }
+                Ok(($(parse_capture::<$value>(captures, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1: A 0);
+impl_from_captures!(2: A 0, B 1);
+impl_from_captures!(3: A 0, B 1, C 2);
+impl_from_captures!(4: A 0, B 1, C 2, D 3);
+impl_from_captures!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_captures!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_captures!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_captures!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+pub fn parse<T: FromCaptures>(pattern: &str, s: &str) -> Result<T, PatternError> {
+    T::from_captures(&captures(pattern, s)?)
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+use crate::{ErasedError, MemoryStats, PartOutcome, STDIN_PATH, SolveError, SolveReport, Verdict, json};
+
+pub trait Reporter {
+    fn report(&mut self, report: &SolveReport) -> io::Result<()>;
+}
+
+fn error_message(error: &SolveError<ErasedError, ErasedError>) -> String {
+    match error {
+        SolveError::IoError(e) => e.to_string(),
+        SolveError::ParseInput(e) | SolveError::Prepare(e) | SolveError::SolvePart1(e) | SolveError::SolvePart2(e) => e.to_string(),
+    }
+}
+
+fn write_memory<W: Write>(writer: &mut W, memory: &Option<MemoryStats>) -> io::Result<()> {
+    match memory {
+        Some(memory) => writeln!(writer, "  Memory:   {} bytes peak, {} allocations", memory.peak_bytes, memory.allocations),
+        None => Ok(()),
+    }
+}
+
+pub struct TextReporter<W> {
+    writer: W,
+}
+
+impl<W: Write> TextReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+        }
+    }
+
+    fn write_part(&mut self, part: usize, outcome: &PartOutcome) -> io::Result<()> {
+        let answer = match outcome {
+            PartOutcome::Solved(answer) => answer,
+            PartOutcome::Skipped => return writeln!(self.writer, "Part {}:\n  Skipped", part),
+            PartOutcome::TimedOut(timeout) => return writeln!(self.writer, "Part {}:\n  Verdict:  TIMEOUT\n  Elapsed:  more than {} seconds", part, timeout.as_secs_f64()),
+        };
+        writeln!(self.writer, "Part {}:\n  Solution: {}", part, answer.value)?;
+        match &answer.verdict {
+            Verdict::Fail { expected } => writeln!(self.writer, "  Verdict:  {} (expected {})", answer.verdict, expected)?,
+            verdict => writeln!(self.writer, "  Verdict:  {}", verdict)?,
+        }
+        writeln!(self.writer, "  Elapsed:  {} seconds", answer.elapsed.as_secs_f64())?;
+        write_memory(&mut self.writer, &answer.memory)
+    }
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn report(&mut self, report: &SolveReport) -> io::Result<()> {
+        if let Some(parse) = &report.parse {
+            writeln!(self.writer, "Parse:\n  Consumed: {} bytes, {} lines\n  Elapsed:  {} seconds", parse.bytes, parse.lines, parse.elapsed.as_secs_f64())?;
+            write_memory(&mut self.writer, &parse.memory)?;
+        }
+        if let Some(prepare) = &report.prepare {
+            writeln!(self.writer, "Prepare:\n  Elapsed:  {} seconds", prepare.elapsed.as_secs_f64())?;
+            write_memory(&mut self.writer, &prepare.memory)?;
+        }
+        if let Some(part_1) = &report.part_1 {
+            self.write_part(1, part_1)?;
+        }
+        if let Some(part_2) = &report.part_2 {
+            self.write_part(2, part_2)?;
+        }
+        if let Some(error) = &report.error {
+            let path = if report.path == STDIN_PATH { "<stdin>" } else { &report.path };
+            writeln!(self.writer, "Error:\n  {}: {}", path, error)?;
+            if let Some(snippet) = &report.snippet {
+                for line in snippet.to_string().lines() {
+                    writeln!(self.writer, "  {}", line)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct JsonReporter<W> {
+    writer: W,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+        }
+    }
+}
+
+fn write_json_memory(out: &mut String, memory: &Option<MemoryStats>) {
+    out.push_str(",\"memory\":");
+    match memory {
+        Some(memory) => write!(out, "{{\"peak_bytes\":{},\"allocations\":{}}}", memory.peak_bytes, memory.allocations).unwrap(),
+        None => out.push_str("null"),
+    }
+}
+
+fn write_json_part(out: &mut String, outcome: &Option<PartOutcome>) {
+    match outcome {
+        Some(PartOutcome::Solved(answer)) => {
+            out.push_str("{\"value\":");
+            json::write_string(out, &answer.value).unwrap();
+            write!(out, ",\"elapsed_ns\":{},\"verdict\":\"{}\"", answer.elapsed.as_nanos(), answer.verdict).unwrap();
+            if let Verdict::Fail { expected } = &answer.verdict {
+                out.push_str(",\"expected\":");
+                json::write_string(out, expected).unwrap();
+            }
+            write_json_memory(out, &answer.memory);
+            out.push('}');
+        },
+        Some(PartOutcome::Skipped) => out.push_str("\"skipped\""),
+        Some(PartOutcome::TimedOut(timeout)) => write!(out, "{{\"elapsed_ns\":{},\"verdict\":\"TIMEOUT\"}}", timeout.as_nanos()).unwrap(),
+        None => out.push_str("null"),
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn report(&mut self, report: &SolveReport) -> io::Result<()> {
+        let mut out = String::new();
+
+        out.push_str("{\"path\":");
+        json::write_string(&mut out, &report.path).unwrap();
+
+        out.push_str(",\"parse\":");
+        match &report.parse {
+            Some(parse) => {
+                write!(out, "{{\"bytes\":{},\"lines\":{},\"elapsed_ns\":{}", parse.bytes, parse.lines, parse.elapsed.as_nanos()).unwrap();
+                write_json_memory(&mut out, &parse.memory);
+                out.push('}');
+            },
+            None => out.push_str("null"),
+        }
+
+        out.push_str(",\"prepare\":");
+        match &report.prepare {
+            Some(prepare) => {
+                write!(out, "{{\"elapsed_ns\":{}", prepare.elapsed.as_nanos()).unwrap();
+                write_json_memory(&mut out, &prepare.memory);
+                out.push('}');
+            },
+            None => out.push_str("null"),
+        }
+
+        out.push_str(",\"part_1\":");
+        write_json_part(&mut out, &report.part_1);
+        out.push_str(",\"part_2\":");
+        write_json_part(&mut out, &report.part_2);
+
+        out.push_str(",\"error\":");
+        match &report.error {
+            Some(error) => {
+                write!(out, "{{\"phase\":\"{}\",\"message\":", error.phase()).unwrap();
+                json::write_string(&mut out, &error_message(error)).unwrap();
+                if let Some(snippet) = &report.snippet {
+                    let span = &snippet.span;
+                    write!(out, ",\"span\":{{\"line\":{},\"column\":{},\"start\":{},\"end\":{}}}", span.line, span.column, span.bytes.start, span.bytes.end).unwrap();
+                }
+                out.push('}');
+            },
+            None => out.push_str("null"),
+        }
+        out.push('}');
+
+        writeln!(self.writer, "{}", out)
+    }
+}
+
+pub struct TsvReporter<W> {
+    writer: W,
+    wrote_header: bool,
+}
+
+impl<W: Write> TsvReporter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            wrote_header: false,
+        }
+    }
+
+    fn write_row(&mut self, path: &str, phase: &str, elapsed_ns: Option<u128>, status: &str, value: &str, memory: &Option<MemoryStats>) -> io::Result<()> {
+        let elapsed_ns = elapsed_ns.map(|e| e.to_string()).unwrap_or_default();
+        let (peak_bytes, allocations) = memory.map(|m| (m.peak_bytes.to_string(), m.allocations.to_string())).unwrap_or_default();
+        writeln!(self.writer, "{}\t{}\t{}\t{}\t{}\t{}\t{}", tsv_field(path), phase, elapsed_ns, status, tsv_field(value), peak_bytes, allocations)
+    }
+}
+
+fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+impl<W: Write> Reporter for TsvReporter<W> {
+    fn report(&mut self, report: &SolveReport) -> io::Result<()> {
+        if !self.wrote_header {
+            writeln!(self.writer, "path\tphase\telapsed_ns\tstatus\tvalue\tpeak_bytes\tallocations")?;
+            self.wrote_header = true;
+        }
+
+        if let Some(parse) = &report.parse {
+            self.write_row(&report.path, "parse", Some(parse.elapsed.as_nanos()), "OK", &format!("{} bytes, {} lines", parse.bytes, parse.lines), &parse.memory)?;
+        }
+        if let Some(prepare) = &report.prepare {
+            self.write_row(&report.path, "prepare", Some(prepare.elapsed.as_nanos()), "OK", "", &prepare.memory)?;
+        }
+        for (phase, outcome) in [("part_1", &report.part_1), ("part_2", &report.part_2)].iter() {
+            match outcome {
+                Some(PartOutcome::Solved(answer)) => self.write_row(&report.path, phase, Some(answer.elapsed.as_nanos()), &answer.verdict.to_string(), &answer.value, &answer.memory)?,
+                Some(PartOutcome::Skipped) => self.write_row(&report.path, phase, None, "SKIPPED", "", &None)?,
+                Some(PartOutcome::TimedOut(timeout)) => self.write_row(&report.path, phase, Some(timeout.as_nanos()), "TIMEOUT", "", &None)?,
+                None => (),
+            }
+        }
+        if let Some(error) = &report.error {
+            self.write_row(&report.path, error.phase(), None, "ERROR", &error_message(error), &None)?;
+        }
+        Ok(())
+    }
+}
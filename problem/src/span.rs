@@ -0,0 +1,133 @@
+use std::{
+    char::ParseCharError,
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    num::{ParseFloatError, ParseIntError},
+    ops::Range,
+    str::ParseBoolError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub bytes: Range<usize>,
+}
+
+pub trait LineError: Error + Send + Sync + 'static {
+    fn columns(&self) -> Option<Range<usize>> {
+        None
+    }
+}
+
+impl LineError for Infallible {}
+impl LineError for ParseIntError {}
+impl LineError for ParseFloatError {}
+impl LineError for ParseBoolError {}
+impl LineError for ParseCharError {}
+
+pub struct Line {
+    pub number: usize,
+    pub offset: usize,
+    pub text: String,
+}
+
+pub(crate) fn line_span(number: usize, offset: usize, text: &str, columns: Range<usize>) -> Span {
+    let start = columns.start.min(text.len());
+    let end = columns.end.clamp(start, text.len());
+    Span {
+        line: number,
+        column: text[..start].chars().count() + 1,
+        bytes: offset + start..offset + end,
+    }
+}
+
+impl Line {
+    pub fn span(&self, columns: Range<usize>) -> Span {
+        line_span(self.number, self.offset, &self.text, columns)
+    }
+
+    pub fn full_span(&self) -> Span {
+        self.span(0..self.text.len())
+    }
+}
+
+pub struct Lines<R> {
+    reader: R,
+    number: usize,
+    offset: usize,
+}
+
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        number: 0,
+        offset: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(read) => {
+                let offset = self.offset;
+                self.number += 1;
+                self.offset += read;
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                Some(Ok(Line {
+                    number: self.number,
+                    offset,
+                    text,
+                }))
+            },
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Snippet {
+    pub span: Span,
+    pub line: String,
+}
+
+impl Snippet {
+    pub fn new(source: &[u8], span: Span) -> Self {
+        let start = span.bytes.start.min(source.len());
+        let line_start = source[..start].iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+        let line_end = source[start..].iter().position(|&b| b == b'\n').map_or(source.len(), |i| start + i);
+        let line = String::from_utf8_lossy(&source[line_start..line_end]).trim_end_matches('\r').to_string();
+        Self {
+            span,
+            line,
+        }
+    }
+}
+
+impl Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.span.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.span.column - 1);
+        // The span is measured in bytes, the carets have to cover as many characters as it does.
+        let start = self.line.char_indices().nth(self.span.column - 1).map_or(self.line.len(), |(i, _)| i);
+        let end = (start + self.span.bytes.len()).min(self.line.len());
+        let carets = "^".repeat(self.line.get(start..end).map_or(0, |spanned| spanned.chars().count()).max(1));
+
+        writeln!(f, "{}--> line {}, column {}", gutter, self.span.line, self.span.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.line)?;
+        write!(f, "{} | {}{}", gutter, indent, carets)
+    }
+}
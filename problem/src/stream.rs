@@ -0,0 +1,197 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    fs::File,
+    io::{BufRead, BufReader},
+    marker::PhantomData,
+    str::FromStr,
+};
+use crate::{Answer, ErasedError, LineError, ParseLinesError, PartOutcome, Plan, Problem, STDIN_PATH, SolveError, SolveOptions, SolveReport, Snippet, Submission, Watch, measured, parse_line, read_input, run_with, solve_with, span};
+
+pub trait RecordSource<T> {
+    fn records(&self) -> Box<dyn Iterator<Item = T> + '_>;
+}
+
+impl<T: Clone> RecordSource<T> for Vec<T> {
+    fn records(&self) -> Box<dyn Iterator<Item = T> + '_> {
+        Box::new(self.iter().cloned())
+    }
+}
+
+// Parts may iterate the records any number of times, each pass re-reads the input from the start.
+pub trait StreamProblem {
+    type Record: FromStr;
+    type Part1Output: Submission;
+    type Part2Output: Submission;
+    type Error: Error + 'static;
+
+    fn part_1(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part1Output, Self::Error>;
+    fn part_2(records: &dyn RecordSource<Self::Record>) -> Result<Self::Part2Output, Self::Error>;
+}
+
+pub struct Streamed<P>(PhantomData<P>);
+
+impl<P: StreamProblem> Problem for Streamed<P>
+where
+    P::Record: Clone,
+    <P::Record as FromStr>::Err: LineError,
+{
+    type Input = Vec<P::Record>;
+    type Part1Output = P::Part1Output;
+    type Part2Output = P::Part2Output;
+    type Error = P::Error;
+
+    fn part_1(input: &Self::Input) -> Result<Self::Part1Output, Self::Error> {
+        P::part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<Self::Part2Output, Self::Error> {
+        P::part_2(input)
+    }
+}
+
+enum Source {
+    File(String),
+    Buffer(Vec<u8>),
+}
+
+type StreamError<E> = (ParseLinesError<E>, Option<Snippet>);
+
+pub struct InputStream<T: FromStr> {
+    source: Source,
+    error: RefCell<Option<StreamError<T::Err>>>,
+}
+
+impl<T: FromStr> InputStream<T> {
+    // Stdin can only be read once, so it is buffered in memory.
+    pub fn open(path: &str) -> Self {
+        let source = if path == STDIN_PATH {
+            match read_input(path) {
+                Ok(contents) => Source::Buffer(contents),
+                Err(e) => {
+                    return Self {
+                        source: Source::Buffer(Vec::new()),
+                        error: RefCell::new(Some((e.into(), None))),
+                    };
+                },
+            }
+        } else {
+            Source::File(path.to_string())
+        };
+        Self {
+            source,
+            error: RefCell::new(None),
+        }
+    }
+
+    pub fn take_error(&self) -> Option<StreamError<T::Err>> {
+        self.error.borrow_mut().take()
+    }
+
+    fn fail(&self, error: ParseLinesError<T::Err>, snippet: Option<Snippet>) {
+        let mut slot = self.error.borrow_mut();
+        if slot.is_none() {
+            *slot = Some((error, snippet));
+        }
+    }
+}
+
+impl<T: FromStr> RecordSource<T> for InputStream<T>
+where
+    T::Err: LineError,
+{
+    fn records(&self) -> Box<dyn Iterator<Item = T> + '_> {
+        let reader: Box<dyn BufRead> = match &self.source {
+            Source::File(path) => match File::open(path) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    self.fail(e.into(), None);
+                    return Box::new(std::iter::empty());
+                },
+            },
+            Source::Buffer(contents) => Box::new(contents.as_slice()),
+        };
+
+        let mut lines = span::lines(reader);
+        Box::new(std::iter::from_fn(move || {
+            if self.error.borrow().is_some() {
+                return None;
+            }
+            match lines.next()? {
+                Ok(line) => match parse_line(&line) {
+                    Ok(record) => Some(record),
+                    Err(e) => {
+                        let snippet = match &e {
+                            ParseLinesError::ParseLine { span, .. } => Some(Snippet {
+                                span: span.clone(),
+                                line: line.text,
+                            }),
+                            ParseLinesError::IoError(_) => None,
+                        };
+                        self.fail(e, snippet);
+                        None
+                    },
+                },
+                Err(e) => {
+                    self.fail(e.into(), None);
+                    None
+                },
+            }
+        }))
+    }
+}
+
+fn solve_stream_into<P: StreamProblem>(path: &str, options: SolveOptions, watch: &Watch, report: &mut SolveReport) -> Result<(), SolveError<ErasedError, ErasedError>>
+where
+    <P::Record as FromStr>::Err: LineError,
+{
+    let stream = InputStream::<P::Record>::open(path);
+    let plan = Plan::read(path, options)?;
+
+    // A part that fails because its input was cut short reports the parse error instead.
+    let check = |report: &mut SolveReport| match stream.take_error() {
+        Some((ParseLinesError::IoError(e), _)) => Err(SolveError::IoError(e)),
+        Some((e, snippet)) => {
+            report.snippet = snippet;
+            Err(SolveError::ParseInput(ErasedError::new(&e)))
+        },
+        None => Ok(()),
+    };
+
+    // Records are parsed as the parts read them, so parsing is timed with the parts.
+    watch.start(1, report);
+    report.part_1 = Some(if plan.runs(1) && !watch.is_cancelled() {
+        let (part_1, duration, memory) = measured(options.memory, || P::part_1(&stream));
+        check(report)?;
+        let part_1 = part_1.map_err(|e| SolveError::SolvePart1(ErasedError::new(&e)))?;
+        PartOutcome::Solved(Answer::new(part_1, duration, memory, plan.expected(1)))
+    } else {
+        PartOutcome::Skipped
+    });
+
+    watch.start(2, report);
+    report.part_2 = Some(if plan.runs(2) && !watch.is_cancelled() {
+        let (part_2, duration, memory) = measured(options.memory, || P::part_2(&stream));
+        check(report)?;
+        let part_2 = part_2.map_err(|e| SolveError::SolvePart2(ErasedError::new(&e)))?;
+        PartOutcome::Solved(Answer::new(part_2, duration, memory, plan.expected(2)))
+    } else {
+        PartOutcome::Skipped
+    });
+
+    Ok(())
+}
+
+pub fn solve_stream<P: StreamProblem>(path: &str, options: SolveOptions) -> SolveReport
+where
+    <P::Record as FromStr>::Err: LineError,
+{
+    solve_with(path, options, solve_stream_into::<P>)
+}
+
+pub fn run_stream<P: StreamProblem>(dir: &str)
+where
+    <P::Record as FromStr>::Err: LineError,
+{
+    run_with(dir, solve_stream::<P>);
+}
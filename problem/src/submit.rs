@@ -0,0 +1,356 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use crate::fetch::{Client, FetchError};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Feedback {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait(Duration),
+    AlreadySolved,
+}
+
+impl Feedback {
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Feedback::Wrong | Feedback::TooHigh | Feedback::TooLow)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Feedback::Right => "RIGHT",
+            Feedback::Wrong => "WRONG",
+            Feedback::TooHigh => "TOO_HIGH",
+            Feedback::TooLow => "TOO_LOW",
+            Feedback::Wait(_) => "WAIT",
+            Feedback::AlreadySolved => "ALREADY_SOLVED",
+        }
+    }
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Right => write!(f, "right"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::TooHigh => write!(f, "wrong, too high"),
+            Feedback::TooLow => write!(f, "wrong, too low"),
+            Feedback::Wait(wait) => write!(f, "wait {}s before submitting again", wait.as_secs()),
+            Feedback::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    IoError(io::Error),
+    FetchError(FetchError),
+    InvalidLog(usize),
+    UnrecognizedResponse(String),
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::IoError(e)
+    }
+}
+
+impl From<FetchError> for SubmitError {
+    fn from(e: FetchError) -> Self {
+        SubmitError::FetchError(e)
+    }
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::IoError(e) => write!(f, "{}", e),
+            SubmitError::FetchError(e) => write!(f, "{}", e),
+            SubmitError::InvalidLog(line) => write!(f, "invalid submission log entry on line {}", line),
+            SubmitError::UnrecognizedResponse(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SubmitError::IoError(e) => Some(e),
+            SubmitError::FetchError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// Only the puzzle's <article> holds the message, the rest of the page is navigation.
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..].find("</article>").map_or(html.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_amount(word: &str) -> Option<u64> {
+    match word {
+        "a" | "an" | "one" => Some(1),
+        word => word.parse().ok(),
+    }
+}
+
+// Reads "4m 49s" style durations and "one minute" or "5 minutes" phrases.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let words = text.split_whitespace().collect::<Vec<_>>();
+    let mut seconds = 0;
+    let mut found = false;
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i].trim_end_matches(['.', ',']);
+        let split = word.find(|c: char| !c.is_ascii_digit()).unwrap_or(word.len());
+        let (amount, unit) = if split > 0 && split < word.len() {
+            (parse_amount(&word[..split]), &word[split..])
+        } else {
+            match (parse_amount(word), words.get(i + 1)) {
+                (Some(amount), Some(next)) => {
+                    i += 1;
+                    (Some(amount), next.trim_end_matches(['.', ',']))
+                },
+                _ => (None, ""),
+            }
+        };
+        let scale = match unit {
+            "h" | "hour" | "hours" => 3600,
+            "m" | "minute" | "minutes" => 60,
+            "s" | "second" | "seconds" => 1,
+            _ => 0,
+        };
+        if let (Some(amount), true) = (amount, scale > 0) {
+            seconds += amount * scale;
+            found = true;
+        }
+        i += 1;
+    }
+    if found { Some(Duration::from_secs(seconds)) } else { None }
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+    Some(&text[from..to])
+}
+
+// Returns the feedback and how long to wait before the next submission.
+pub fn parse_feedback(html: &str) -> Result<(Feedback, Option<Duration>), SubmitError> {
+    let text = article_text(html);
+    let lower = text.to_lowercase();
+    let cooldown = between(&lower, "wait ", " before trying again").and_then(parse_duration);
+
+    if lower.contains("that's the right answer") {
+        Ok((Feedback::Right, None))
+    } else if lower.contains("you gave an answer too recently") {
+        let wait = between(&lower, "you have ", " left to wait").and_then(parse_duration).unwrap_or_default();
+        Ok((Feedback::Wait(wait), Some(wait)))
+    } else if lower.contains("did you already complete it") {
+        Ok((Feedback::AlreadySolved, None))
+    } else if lower.contains("that's not the right answer") {
+        let feedback = if lower.contains("too high") {
+            Feedback::TooHigh
+        } else if lower.contains("too low") {
+            Feedback::TooLow
+        } else {
+            Feedback::Wrong
+        };
+        Ok((feedback, cooldown))
+    } else {
+        Err(SubmitError::UnrecognizedResponse(text))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Attempt {
+    pub timestamp_ms: u128,
+    pub part: usize,
+    pub feedback: Feedback,
+    pub wait_until_ms: u128,
+    pub answer: String,
+}
+
+pub fn submissions_path(input: &Path) -> PathBuf {
+    let mut name = input.as_os_str().to_owned();
+    name.push(".submissions");
+    PathBuf::from(name)
+}
+
+fn now_ms() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis()
+}
+
+// Every answer that was sent for a day, one tab separated attempt per line.
+#[derive(Default)]
+pub struct SubmissionLog {
+    pub attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut attempts = Vec::new();
+        for (i, line) in contents.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let invalid = || SubmitError::InvalidLog(i + 1);
+            let fields = line.splitn(5, '\t').collect::<Vec<_>>();
+            if fields.len() != 5 {
+                return Err(invalid());
+            }
+            let timestamp_ms = fields[0].parse().map_err(|_| invalid())?;
+            let wait_until_ms = fields[3].parse().map_err(|_| invalid())?;
+            let feedback = match fields[2] {
+                "RIGHT" => Feedback::Right,
+                "WRONG" => Feedback::Wrong,
+                "TOO_HIGH" => Feedback::TooHigh,
+                "TOO_LOW" => Feedback::TooLow,
+                "WAIT" => Feedback::Wait(Duration::from_millis(u128::saturating_sub(wait_until_ms, timestamp_ms) as u64)),
+                "ALREADY_SOLVED" => Feedback::AlreadySolved,
+                _ => return Err(invalid()),
+            };
+            attempts.push(Attempt {
+                timestamp_ms,
+                part: fields[1].parse().map_err(|_| invalid())?,
+                feedback,
+                wait_until_ms,
+                answer: fields[4].to_string(),
+            });
+        }
+        Ok(Self {
+            attempts,
+        })
+    }
+
+    pub fn append(&mut self, path: &Path, attempt: Attempt) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}\t{}\t{}\t{}\t{}", attempt.timestamp_ms, attempt.part, attempt.feedback.name(), attempt.wait_until_ms, attempt.answer)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    pub fn cooldown(&self, now_ms: u128) -> Option<Duration> {
+        let until = self.attempts.iter().map(|attempt| attempt.wait_until_ms).max()?;
+        if until > now_ms { Some(Duration::from_millis((until - now_ms) as u64)) } else { None }
+    }
+
+    // What submitting `answer` is already known to give, without asking again. Numeric answers are also
+    // checked against the bounds from earlier too high and too low guesses.
+    pub fn known(&self, part: usize, answer: &str) -> Option<Feedback> {
+        let attempts = self.attempts.iter().filter(|attempt| attempt.part == part).collect::<Vec<_>>();
+        if let Some(right) = attempts.iter().find(|attempt| attempt.feedback == Feedback::Right) {
+            return Some(if right.answer == answer { Feedback::Right } else { Feedback::AlreadySolved });
+        }
+        if let Some(rejected) = attempts.iter().find(|attempt| attempt.answer == answer && attempt.feedback.is_rejection()) {
+            return Some(rejected.feedback);
+        }
+
+        let value = i128::from_str(answer).ok()?;
+        let bound = |feedback| attempts.iter().filter(move |attempt| attempt.feedback == feedback).filter_map(|attempt| attempt.answer.parse::<i128>().ok());
+        if bound(Feedback::TooHigh).any(|high| value >= high) {
+            Some(Feedback::TooHigh)
+        } else if bound(Feedback::TooLow).any(|low| value <= low) {
+            Some(Feedback::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Submission {
+    pub feedback: Feedback,
+    pub sent: bool,
+}
+
+fn url_encode(s: &str) -> String {
+    let mut encoded = String::new();
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(b as char),
+            b => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+// Answers with a known outcome and answers during a cooldown aren't sent, the returned feedback says why.
+pub fn submit(client: &Client, day: usize, part: usize, answer: &str, log_path: &Path) -> Result<Submission, SubmitError> {
+    let mut log = SubmissionLog::load(log_path)?;
+    if let Some(feedback) = log.known(part, answer) {
+        return Ok(Submission {
+            feedback,
+            sent: false,
+        });
+    }
+    let now = now_ms();
+    if let Some(wait) = log.cooldown(now) {
+        return Ok(Submission {
+            feedback: Feedback::Wait(wait),
+            sent: false,
+        });
+    }
+
+    let body = format!("level={}&answer={}", part, url_encode(answer));
+    let response = client.send("POST", &client.day_url(day, "/answer"), &[("Content-Type", "application/x-www-form-urlencoded")], body.as_bytes())?;
+    if response.status != 200 {
+        return Err(FetchError::Status {
+            status: response.status,
+            body: response.text(),
+        }
+        .into());
+    }
+
+    let (feedback, cooldown) = parse_feedback(&response.text())?;
+    log.append(log_path, Attempt {
+        timestamp_ms: now,
+        part,
+        feedback,
+        wait_until_ms: cooldown.map_or(0, |cooldown| now + cooldown.as_millis()),
+        answer: answer.to_string(),
+    })?;
+    Ok(Submission {
+        feedback,
+        sent: true,
+    })
+}
+
+// Writes a confirmed answer into the input's answers file so later runs check against it.
+pub fn record_answer(input: &Path, part: usize, answer: &str) -> io::Result<()> {
+    let mut path = input.as_os_str().to_owned();
+    path.push(".answers");
+    let mut lines = match fs::read_to_string(&path) {
+        Ok(contents) => contents.lines().map(str::to_string).collect::<Vec<_>>(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    if lines.len() < part {
+        lines.resize(part, String::new());
+    }
+    lines[part - 1] = answer.to_string();
+    fs::write(&path, lines.join("\n") + "\n")
+}
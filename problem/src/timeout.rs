@@ -0,0 +1,108 @@
+use std::{
+    panic,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::Instant,
+};
+use crate::{ErasedError, PartOutcome, SolveError, SolveOptions, SolveReport};
+
+// Matches the main thread's stack, some days recurse deeply.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+pub(crate) type SolveInto = fn(&str, SolveOptions, &Watch, &mut SolveReport) -> Result<(), SolveError<ErasedError, ErasedError>>;
+
+enum Event {
+    Started(usize, SolveReport, Instant),
+    Finished(SolveReport),
+}
+
+pub(crate) struct Watch {
+    events: Option<Sender<Event>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Watch {
+    fn new(events: Option<Sender<Event>>) -> Self {
+        Self {
+            events,
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub(crate) fn start(&self, part: usize, report: &SolveReport) {
+        if let Some(sender) = &self.events {
+            let _ = sender.send(Event::Started(part, report.progress(), Instant::now()));
+        }
+    }
+
+    // Set once the runner stops waiting, the solve checks it between stages.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+fn solve_report(path: &str, options: SolveOptions, watch: &Watch, solve_into: SolveInto) -> SolveReport {
+    let mut report = SolveReport::new(path);
+    if let Err(e) = solve_into(path, options, watch, &mut report) {
+        report.error = Some(e);
+    }
+    report
+}
+
+// A running stage can't be interrupted, so one that runs out of time is left to finish on its own thread and
+// is cancelled before the next one starts. Until then it keeps a core busy, which slows down whatever runs
+// next, and it's only stopped for good when the process exits. The report marks the part as timed out and any
+// part after it as skipped, parsing and preparing count toward the first part.
+pub(crate) fn solve_with(path: &str, options: SolveOptions, solve_into: SolveInto) -> SolveReport {
+    let timeout = match options.timeout {
+        Some(timeout) => timeout,
+        None => return solve_report(path, options, &Watch::new(None), solve_into),
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let watch = Watch::new(Some(sender.clone()));
+    let cancelled = watch.cancelled.clone();
+    let owned_path = path.to_string();
+    let spawned = thread::Builder::new().stack_size(WORKER_STACK_SIZE).spawn(move || {
+        let report = solve_report(&owned_path, options, &watch, solve_into);
+        let _ = sender.send(Event::Finished(report));
+    });
+    let worker = match spawned {
+        Ok(worker) => worker,
+        Err(e) => {
+            let mut report = SolveReport::new(path);
+            report.error = Some(e.into());
+            return report;
+        },
+    };
+
+    let mut running: Option<(usize, SolveReport, Instant)> = None;
+    loop {
+        let event = match &running {
+            Some((_, _, start)) => receiver.recv_timeout(timeout.saturating_sub(start.elapsed())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match event {
+            Ok(Event::Started(part, report, start)) => running = Some((part, report, start)),
+            Ok(Event::Finished(report)) => return report,
+            Err(RecvTimeoutError::Timeout) => {
+                cancelled.store(true, Ordering::Relaxed);
+                let (part, mut report, _) = running.unwrap();
+                let outcome = Some(PartOutcome::TimedOut(timeout));
+                if part == 1 {
+                    report.part_1 = outcome;
+                } else {
+                    report.part_2 = outcome;
+                }
+                report.part_1.get_or_insert(PartOutcome::Skipped);
+                report.part_2.get_or_insert(PartOutcome::Skipped);
+                return report;
+            },
+            Err(RecvTimeoutError::Disconnected) => panic::resume_unwind(worker.join().unwrap_err()),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+#![allow(dead_code)]
+
+use std::{
+    env,
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    process,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+    pub chunked: bool,
+}
+
+impl Reply {
+    pub fn ok(body: &str) -> Self {
+        Self::status(200, body)
+    }
+
+    pub fn status(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            body: body.to_string(),
+            chunked: false,
+        }
+    }
+
+    pub fn chunked(self) -> Self {
+        Self {
+            chunked: true,
+            ..self
+        }
+    }
+}
+
+// Stands in for the puzzle server on a local port, replies come from `handler` and every request is kept.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(handler: impl Fn(&Request) -> Reply + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                let request = read_request(&stream);
+                recorded.lock().unwrap().push(request.clone());
+                write_reply(stream, handler(&request));
+            }
+        });
+        Self {
+            url,
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Request {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap().to_string();
+    let path = parts.next().unwrap().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (key, value) = line.split_once(':').unwrap();
+        headers.push((key.trim().to_string(), value.trim().to_string()));
+    }
+
+    let length = headers.iter().find(|(key, _)| key.eq_ignore_ascii_case("Content-Length")).map_or(0, |(_, value)| value.parse().unwrap());
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+fn write_reply(mut stream: TcpStream, reply: Reply) {
+    let mut response = format!("HTTP/1.1 {} Mock\r\nContent-Type: text/plain\r\nConnection: close\r\n", reply.status);
+    if reply.chunked {
+        response.push_str("Transfer-Encoding: chunked\r\n\r\n");
+        for chunk in reply.body.as_bytes().chunks(3) {
+            response.push_str(&format!("{:x}\r\n{}\r\n", chunk.len(), String::from_utf8_lossy(chunk)));
+        }
+        response.push_str("0\r\n\r\n");
+    } else {
+        response.push_str(&format!("Content-Length: {}\r\n\r\n{}", reply.body.len(), reply.body));
+    }
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("problem-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,148 @@
+use std::{convert::Infallible, error::Error, fmt, num::ParseIntError};
+use problem::{Line, ProblemInput};
+
+#[derive(Debug)]
+struct ParsePairError;
+
+impl fmt::Display for ParsePairError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected two numbers joined by `-`")
+    }
+}
+
+impl Error for ParsePairError {}
+
+fn parse_pair(s: &str) -> Result<(i32, i32), ParsePairError> {
+    let (a, b) = s.split_once('-').ok_or(ParsePairError)?;
+    Ok((a.parse().map_err(|_| ParsePairError)?, b.parse().map_err(|_| ParsePairError)?))
+}
+
+fn count_lines(lines: &[Line]) -> Result<usize, Infallible> {
+    Ok(lines.len())
+}
+
+#[derive(Debug, ProblemInput)]
+struct Header {
+    count: usize,
+    #[problem_input(separator = ",")]
+    values: Vec<i32>,
+    #[problem_input(with = parse_pair)]
+    pair: (i32, i32),
+}
+
+fn header(input: &str) -> Result<Header, ParseHeaderError> {
+    Header::parse(input.as_bytes())
+}
+
+#[test]
+fn lines_mode_parses_a_field_per_line() {
+    let header = header("3\n1,2,3\n4-5\n\n").unwrap();
+    assert_eq!(header.count, 3);
+    assert_eq!(header.values, vec![1, 2, 3]);
+    assert_eq!(header.pair, (4, 5));
+}
+
+#[test]
+fn lines_mode_missing_field() {
+    let error = header("3\n1,2\n").unwrap_err();
+    assert!(matches!(error, ParseHeaderError::Missing("pair")));
+    assert_eq!(error.to_string(), "missing pair");
+}
+
+#[test]
+fn lines_mode_invalid_field_chains_its_error() {
+    let error = header("x\n1\n4-5\n").unwrap_err();
+    assert!(matches!(&error, ParseHeaderError::Invalid { field: "count", span, .. } if span.line == 1));
+    assert_eq!(error.to_string(), "invalid count on line 1: invalid digit found in string");
+    assert!(error.source().unwrap().is::<ParseIntError>());
+}
+
+#[test]
+fn lines_mode_with_parser_error() {
+    let error = header("3\n1\n4\n").unwrap_err();
+    assert!(error.source().unwrap().is::<ParsePairError>());
+    assert_eq!(error.to_string(), "invalid pair on line 3: expected two numbers joined by `-`");
+}
+
+#[test]
+fn separator_error_spans_the_piece() {
+    let error = header("3\n1,x,3\n4-5\n").unwrap_err();
+    let span = Header::error_span(&error).unwrap();
+    assert_eq!((span.line, span.column), (2, 3));
+    assert_eq!(span.bytes, 4..5);
+}
+
+#[test]
+fn lines_mode_rejects_extra_lines() {
+    let error = header("3\n1\n4-5\n\nextra\n").unwrap_err();
+    assert!(matches!(&error, ParseHeaderError::UnexpectedLine(span) if span.line == 5));
+    assert_eq!(error.to_string(), "unexpected line 5");
+    assert_eq!(Header::error_span(&error).map(|span| span.line), Some(5));
+}
+
+#[derive(Debug, ProblemInput)]
+#[problem_input(sections)]
+struct Notes {
+    #[problem_input(title = "rules:", each_line)]
+    rules: Vec<i32>,
+    #[problem_input(line, separator = " ")]
+    words: Vec<String>,
+    #[problem_input(group_with = count_lines)]
+    count: usize,
+    numbers: Vec<i32>,
+    #[problem_input(line, with = parse_pair)]
+    pair: (i32, i32),
+}
+
+fn notes(input: &str) -> Result<Notes, ParseNotesError> {
+    Notes::parse(input.as_bytes())
+}
+
+const NOTES: &str = "rules:\n1\n2\n\na b c\n\nx\ny\n\n7\n8\n\n1-2\n";
+
+#[test]
+fn sections_mode_parses_a_field_per_section() {
+    let notes = notes(NOTES).unwrap();
+    assert_eq!(notes.rules, vec![1, 2]);
+    assert_eq!(notes.words, vec!["a", "b", "c"]);
+    assert_eq!(notes.count, 2);
+    assert_eq!(notes.numbers, vec![7, 8]);
+    assert_eq!(notes.pair, (1, 2));
+}
+
+#[test]
+fn sections_mode_expects_title() {
+    let error = notes(&NOTES.replacen("rules:", "rulez:", 1)).unwrap_err();
+    assert!(matches!(&error, ParseNotesError::ExpectedTitle { field: "rules", title: "rules:", span } if span.line == 1));
+    assert_eq!(error.to_string(), "expected `rules:` before rules on line 1");
+    assert_eq!(Notes::error_span(&error).map(|span| span.line), Some(1));
+}
+
+#[test]
+fn sections_mode_line_section_rejects_extra_lines() {
+    let error = notes(&NOTES.replacen("a b c\n", "a b c\nd\n", 1)).unwrap_err();
+    assert!(matches!(&error, ParseNotesError::UnexpectedLine(span) if span.line == 6));
+}
+
+#[test]
+fn sections_mode_each_line_error_has_its_line() {
+    let error = notes(&NOTES.replacen("2\n", "z\n", 1)).unwrap_err();
+    assert!(matches!(&error, ParseNotesError::Invalid { field: "rules", span, .. } if span.line == 3));
+}
+
+#[test]
+fn sections_mode_group_error_has_its_line() {
+    let error = notes(&NOTES.replacen("8\n", "eight\n", 1)).unwrap_err();
+    assert!(matches!(&error, ParseNotesError::Invalid { field: "numbers", span, .. } if (span.line, span.column) == (11, 1)));
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn sections_mode_missing_and_extra_sections() {
+    let error = notes("rules:\n1\n\na\n\nx\n\n7\n").unwrap_err();
+    assert!(matches!(error, ParseNotesError::Missing("pair")));
+
+    let error = notes(&format!("{}\nmore\n", NOTES)).unwrap_err();
+    assert!(matches!(&error, ParseNotesError::UnexpectedSection(span) if span.line == 15));
+    assert_eq!(error.to_string(), "unexpected section at line 15");
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use std::fs;
+use common::{MockServer, Reply, temp_dir};
+use problem::fetch::{Client, FetchError, checksum, checksum_path, fetch_cached};
+
+const INPUT: &str = "1721\n979\n366\n";
+
+fn client(server: &MockServer) -> Client {
+    Client::new(&server.url, "token", 2020)
+}
+
+#[test]
+fn downloads_missing_input() {
+    let server = MockServer::start(|_| Reply::ok(INPUT));
+    let dir = temp_dir("downloads_missing_input");
+
+    let input = fetch_cached(&client(&server), 7, dir.to_str().unwrap()).unwrap();
+    assert!(input.downloaded);
+    assert_eq!(input.path, dir.join("inputs/real.txt"));
+    assert_eq!(input.checksum, checksum(INPUT.as_bytes()));
+    assert_eq!(fs::read_to_string(&input.path).unwrap(), INPUT);
+    assert_eq!(fs::read_to_string(checksum_path(&input.path)).unwrap(), format!("fnv1a64 {:016x}\n", input.checksum));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2020/day/7/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=token"));
+}
+
+#[test]
+fn cached_input_is_not_downloaded_again() {
+    let server = MockServer::start(|_| Reply::ok(INPUT));
+    let dir = temp_dir("cached_input_is_not_downloaded_again");
+
+    let first = fetch_cached(&client(&server), 1, dir.to_str().unwrap()).unwrap();
+    let second = fetch_cached(&client(&server), 1, dir.to_str().unwrap()).unwrap();
+    assert!(first.downloaded);
+    assert!(!second.downloaded);
+    assert_eq!(first.checksum, second.checksum);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn modified_input_fails_checksum() {
+    let server = MockServer::start(|_| Reply::ok(INPUT));
+    let dir = temp_dir("modified_input_fails_checksum");
+
+    let input = fetch_cached(&client(&server), 1, dir.to_str().unwrap()).unwrap();
+    fs::write(&input.path, "1721\n").unwrap();
+    match fetch_cached(&client(&server), 1, dir.to_str().unwrap()) {
+        Err(FetchError::ChecksumMismatch { expected, found, .. }) => {
+            assert_eq!(expected, checksum(INPUT.as_bytes()));
+            assert_eq!(found, checksum(b"1721\n"));
+        },
+        _ => panic!("expected a checksum mismatch"),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn existing_input_gets_a_checksum() {
+    let server = MockServer::start(|_| Reply::ok(INPUT));
+    let dir = temp_dir("existing_input_gets_a_checksum");
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(dir.join("inputs/real.txt"), "committed\n").unwrap();
+
+    let input = fetch_cached(&client(&server), 1, dir.to_str().unwrap()).unwrap();
+    assert!(!input.downloaded);
+    assert_eq!(fs::read_to_string(checksum_path(&input.path)).unwrap(), format!("fnv1a64 {:016x}\n", checksum(b"committed\n")));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn error_status_caches_nothing() {
+    let server = MockServer::start(|_| Reply::status(404, "Please don't repeatedly request this endpoint before it unlocks!"));
+    let dir = temp_dir("error_status_caches_nothing");
+
+    match fetch_cached(&client(&server), 25, dir.to_str().unwrap()) {
+        Err(FetchError::Status { status, body }) => {
+            assert_eq!(status, 404);
+            assert!(body.contains("before it unlocks"));
+        },
+        _ => panic!("expected an error status"),
+    }
+    assert!(!dir.join("inputs/real.txt").exists());
+}
+
+#[test]
+fn chunked_response_is_decoded() {
+    let server = MockServer::start(|_| Reply::ok(INPUT).chunked());
+    assert_eq!(client(&server).fetch_input(1).unwrap(), INPUT.as_bytes());
+}
+
+#[test]
+fn base_url_trailing_slash_is_ignored() {
+    let client = Client::new("http://localhost:8080/", "token", 2020);
+    assert_eq!(client.day_url(3, "/input"), "http://localhost:8080/2020/day/3/input");
+}
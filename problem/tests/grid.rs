@@ -0,0 +1,58 @@
+use std::error::Error;
+use grid::Grid;
+use problem::{FromGroup, InvalidCharError, ParseGridError, ProblemInput, span::lines};
+
+fn grid(input: &str) -> Result<Grid<bool>, ParseGridError<InvalidCharError>> {
+    Grid::<bool>::parse(input.as_bytes())
+}
+
+#[test]
+fn parses_rows() {
+    let grid = grid("#.#\n.#.\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert!(*grid.get(0, 0));
+    assert!(!*grid.get(1, 0));
+    assert!(*grid.get(1, 1));
+}
+
+#[test]
+fn empty_grid_is_an_error() {
+    assert!(matches!(grid(""), Err(ParseGridError::Empty)));
+}
+
+#[test]
+fn uneven_row_is_an_error() {
+    let error = grid("#.#\n.#.\n.#\n").unwrap_err();
+    assert!(matches!(&error, ParseGridError::UnevenRow { span, expected: 3, found: 2 } if span.line == 3));
+    assert_eq!(error.to_string(), "line 3 has width 2, expected 3");
+    assert_eq!(<Grid<bool> as ProblemInput>::error_span(&error).unwrap().bytes, 8..10);
+}
+
+#[test]
+fn invalid_char_has_row_and_column() {
+    let error = grid("#.#\n.#.\n.x#\n").unwrap_err();
+    match &error {
+        ParseGridError::InvalidChar { span, error } => {
+            assert_eq!((span.line, span.column), (3, 2));
+            assert_eq!(span.bytes, 9..10);
+            assert_eq!(error.0, 'x');
+        },
+        _ => panic!("unexpected error {:?}", error),
+    }
+    assert_eq!(error.to_string(), "line 3, column 2: invalid character `x`");
+    assert!(error.source().unwrap().is::<InvalidCharError>());
+}
+
+#[test]
+fn width_is_counted_in_characters() {
+    let grid = Grid::<char>::parse("é.\n.é\n".as_bytes()).unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(*grid.get(1, 1), 'é');
+}
+
+#[test]
+fn group_keeps_its_line_numbers() {
+    let group = lines("\n\n##\n#?\n".as_bytes()).skip(2).collect::<Result<Vec<_>, _>>().unwrap();
+    let error = Grid::<bool>::from_group(&group).unwrap_err();
+    assert!(matches!(&error, ParseGridError::InvalidChar { span, .. } if (span.line, span.column) == (4, 2)));
+}
@@ -0,0 +1,88 @@
+use problem::{FromGroup, Groups, ParseGroupsError, ParseSectionsError, ParseTitledError, ProblemInput, Sections, Titled, span::lines};
+
+fn groups(input: &str) -> Result<Vec<Vec<i32>>, ParseGroupsError<<Vec<i32> as FromGroup>::Err>> {
+    Groups::<Vec<i32>>::parse(input.as_bytes()).map(|groups| groups.0)
+}
+
+#[test]
+fn splits_on_blank_lines() {
+    assert_eq!(groups("1\n2\n\n3\n").unwrap(), vec![vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn multiple_blank_lines_are_one_separator() {
+    assert_eq!(groups("1\n\n\n  \n2\n").unwrap(), vec![vec![1], vec![2]]);
+}
+
+#[test]
+fn leading_and_trailing_blank_lines_are_ignored() {
+    assert_eq!(groups("\n1\n\n2\n\n").unwrap(), vec![vec![1], vec![2]]);
+    assert_eq!(groups("").unwrap(), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn group_error_has_group_and_line() {
+    let error = groups("1\n\n2\nx\n").unwrap_err();
+    match &error {
+        ParseGroupsError::ParseGroup { group_number, span, .. } => {
+            assert_eq!(*group_number, 2);
+            assert_eq!((span.line, span.column), (4, 1));
+        },
+        _ => panic!("unexpected error {:?}", error),
+    }
+    assert_eq!(error.to_string(), "group 2: line 4, column 1: invalid digit found in string");
+    assert_eq!(Groups::<Vec<i32>>::error_span(&error).unwrap().bytes, 5..6);
+}
+
+type TwoSections = Sections<(Vec<i32>, Vec<String>)>;
+
+#[test]
+fn parses_sections() {
+    let Sections((numbers, words)) = TwoSections::parse("1\n2\n\n\na\nb\n".as_bytes()).unwrap();
+    assert_eq!(numbers, vec![1, 2]);
+    assert_eq!(words, vec!["a", "b"]);
+}
+
+#[test]
+fn missing_section_is_an_error() {
+    let error = TwoSections::parse("1\n2\n\n".as_bytes()).err().unwrap();
+    assert!(matches!(error, ParseSectionsError::MissingSection(2)));
+    assert_eq!(error.to_string(), "missing section 2");
+    assert!(TwoSections::error_span(&error).is_none());
+}
+
+#[test]
+fn extra_section_is_an_error() {
+    let error = TwoSections::parse("1\n\na\n\nb\n".as_bytes()).err().unwrap();
+    assert!(matches!(&error, ParseSectionsError::UnexpectedSection { section_number: 3, span } if span.line == 5));
+    assert_eq!(error.to_string(), "unexpected section 3 at line 5");
+}
+
+#[test]
+fn section_error_has_section_and_line() {
+    let error = Sections::<(Vec<i32>, Vec<i32>)>::parse("1\n\n2\n3x\n".as_bytes()).err().unwrap();
+    assert!(matches!(&error, ParseSectionsError::ParseSection { section_number: 2, span, .. } if (span.line, span.column) == (4, 1)));
+    assert_eq!(error.to_string(), "section 2: line 4, column 1: invalid digit found in string");
+}
+
+#[test]
+fn titled_group_splits_off_title() {
+    let group = lines("Tile 7:\n1\n2\n".as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+    let titled = Titled::<Vec<i32>>::from_group(&group).unwrap();
+    assert_eq!(titled.title, "Tile 7:");
+    assert_eq!(titled.value, vec![1, 2]);
+}
+
+#[test]
+fn titled_empty_group_expects_title() {
+    let error = Titled::<Vec<i32>>::from_group(&[]).err().unwrap();
+    assert!(matches!(error, ParseTitledError::ExpectedTitle));
+    assert_eq!(error.to_string(), "expected a title line");
+}
+
+#[test]
+fn titled_value_error_keeps_its_span() {
+    let group = lines("Tile 7:\n1\nx\n".as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+    let error = Titled::<Vec<i32>>::from_group(&group).err().unwrap();
+    assert_eq!(Titled::<Vec<i32>>::error_span(&error).map(|span| span.line), Some(3));
+}
@@ -0,0 +1,76 @@
+use std::{error::Error, num::ParseIntError};
+use problem::{LineError, PatternError, parse_pattern, pattern::captures};
+
+#[test]
+fn parses_typed_tuple() {
+    let (min, max, letter, password): (usize, usize, char, String) = parse_pattern!("{}-{} {}: {}", "1-3 a: abcde").unwrap();
+    assert_eq!((min, max, letter, password.as_str()), (1, 3, 'a', "abcde"));
+}
+
+#[test]
+fn placeholders_stop_at_the_first_following_literal() {
+    let (a, b): (String, String) = parse_pattern!("{}-{}", "1-2-3").unwrap();
+    assert_eq!((a.as_str(), b.as_str()), ("1", "2-3"));
+
+    let (a, b): (String, String) = parse_pattern!("{} or {}", "x or y or z").unwrap();
+    assert_eq!((a.as_str(), b.as_str()), ("x", "y or z"));
+}
+
+#[test]
+fn trailing_placeholder_takes_the_rest() {
+    let (address, value): (u64, u64) = parse_pattern!("mem[{}] = {}", "mem[8] = 11").unwrap();
+    assert_eq!((address, value), (8, 11));
+
+    let (rest,): (String,) = parse_pattern!("mask = {}", "mask = ").unwrap();
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn trailing_literal_must_end_the_line() {
+    let (count,): (u32,) = parse_pattern!("{} bags", "3 bags").unwrap();
+    assert_eq!(count, 3);
+
+    let error = parse_pattern!("{} bags", "3 bag").map(|(_,): (u32,)| ()).unwrap_err();
+    assert!(matches!(&error, PatternError::ExpectedLiteral { literal, position: 5 } if literal == " bags"));
+}
+
+#[test]
+fn missing_literal_is_an_error() {
+    let error = parse_pattern!("{}-{}", "13").map(|(_, _): (u32, u32)| ()).unwrap_err();
+    assert!(matches!(&error, PatternError::ExpectedLiteral { literal, position: 0 } if literal == "-"));
+    assert_eq!(error.to_string(), "expected `-` at column 1");
+    assert_eq!(error.columns(), Some(0..1));
+
+    let error = parse_pattern!("mem[{}] = {}", "mask = 1").map(|(_, _): (u64, u64)| ()).unwrap_err();
+    assert!(matches!(&error, PatternError::ExpectedLiteral { literal, position: 0 } if literal == "mem["));
+}
+
+#[test]
+fn pattern_without_placeholders_must_match_exactly() {
+    assert!(captures("abc", "abc").unwrap().is_empty());
+    assert!(matches!(captures("abc", "abcd"), Err(PatternError::ExpectedLiteral { position: 3, .. })));
+}
+
+#[test]
+fn parses_one_and_eight_tuples() {
+    let (value,): (i32,) = parse_pattern!("#{}", "#-7").unwrap();
+    assert_eq!(value, -7);
+
+    let values: (u8, u8, u8, u8, u8, u8, u8, u8) = parse_pattern!("{},{},{},{},{},{},{},{}", "1,2,3,4,5,6,7,8").unwrap();
+    assert_eq!(values, (1, 2, 3, 4, 5, 6, 7, 8));
+}
+
+#[test]
+fn error_names_the_failed_placeholder() {
+    let error = parse_pattern!("{}-{}-{}", "1-x-3").map(|(_, _, _): (u32, u32, u32)| ()).unwrap_err();
+    assert!(matches!(&error, PatternError::InvalidPlaceholder { placeholder: 2, value, .. } if value == "x"));
+    assert_eq!(error.to_string(), "invalid value `x` for placeholder 2: invalid digit found in string");
+    assert_eq!(error.columns(), Some(2..3));
+    assert!(error.source().unwrap().is::<ParseIntError>());
+}
+
+#[test]
+fn placeholder_count_must_match_tuple() {
+    let error = parse_pattern!("{}", "1").map(|(_, _): (u32, u32)| ()).unwrap_err();
+    assert!(matches!(error, PatternError::PlaceholderCount { expected: 2, found: 1 }));
+}
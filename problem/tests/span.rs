@@ -0,0 +1,28 @@
+use std::ops::Range;
+use problem::{Snippet, span::lines};
+
+fn snippet(source: &str, line: usize, columns: Range<usize>) -> String {
+    let line = lines(source.as_bytes()).nth(line - 1).unwrap().unwrap();
+    Snippet::new(source.as_bytes(), line.span(columns)).to_string()
+}
+
+#[test]
+fn renders_carets_under_span() {
+    assert_eq!(snippet("1\n2x4\n", 2, 1..2), " --> line 2, column 2\n  |\n2 | 2x4\n  |  ^");
+}
+
+#[test]
+fn renders_one_caret_for_empty_span() {
+    assert_eq!(snippet("abc\n", 1, 3..3), " --> line 1, column 4\n  |\n1 | abc\n  |    ^");
+}
+
+#[test]
+fn counts_characters_on_non_ascii_lines() {
+    assert_eq!(snippet("héllo wörld!\n", 1, 7..13), " --> line 1, column 7\n  |\n1 | héllo wörld!\n  |       ^^^^^");
+}
+
+#[test]
+fn widens_gutter_for_long_line_numbers() {
+    let source = "x\n".repeat(9) + "a = b\n";
+    assert_eq!(snippet(&source, 10, 4..5), "  --> line 10, column 5\n   |\n10 | a = b\n   |     ^");
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use std::{fs, path::PathBuf, time::Duration};
+use common::{MockServer, Reply, temp_dir};
+use problem::{
+    fetch::Client,
+    submit::{Feedback, SubmissionLog, SubmitError, parse_feedback, record_answer, submit},
+};
+
+const RIGHT: &str = "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer to saving your vacation.</p></article></main>";
+const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2020/day/5\">Return to Day 5</a>]</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 49s left to wait.</p></article></main>";
+const ALREADY_SOLVED: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>";
+
+fn client(server: &MockServer) -> Client {
+    Client::new(&server.url, "token", 2020)
+}
+
+fn log_path(name: &str) -> PathBuf {
+    temp_dir(name).join("real.txt.submissions")
+}
+
+#[test]
+fn parses_feedback() {
+    assert_eq!(parse_feedback(RIGHT).unwrap(), (Feedback::Right, None));
+    assert_eq!(parse_feedback(WRONG).unwrap(), (Feedback::Wrong, Some(Duration::from_secs(60))));
+    assert_eq!(parse_feedback(TOO_HIGH).unwrap(), (Feedback::TooHigh, None));
+    assert_eq!(parse_feedback(TOO_LOW).unwrap(), (Feedback::TooLow, Some(Duration::from_secs(300))));
+    assert_eq!(parse_feedback(TOO_RECENT).unwrap(), (Feedback::Wait(Duration::from_secs(289)), Some(Duration::from_secs(289))));
+    assert_eq!(parse_feedback(ALREADY_SOLVED).unwrap(), (Feedback::AlreadySolved, None));
+    assert!(matches!(parse_feedback("<article><p>Maintenance</p></article>"), Err(SubmitError::UnrecognizedResponse(text)) if text == "Maintenance"));
+}
+
+#[test]
+fn posts_answer_and_logs_it() {
+    let server = MockServer::start(|_| Reply::ok(RIGHT));
+    let log = log_path("posts_answer_and_logs_it");
+
+    let submission = submit(&client(&server), 5, 2, "a b&c", &log).unwrap();
+    assert_eq!(submission.feedback, Feedback::Right);
+    assert!(submission.sent);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2020/day/5/answer");
+    assert_eq!(requests[0].body, "level=2&answer=a%20b%26c");
+    assert_eq!(requests[0].header("Content-Type"), Some("application/x-www-form-urlencoded"));
+    assert_eq!(requests[0].header("Cookie"), Some("session=token"));
+
+    let attempts = SubmissionLog::load(&log).unwrap().attempts;
+    assert_eq!(attempts.len(), 1);
+    assert_eq!((attempts[0].part, attempts[0].feedback, attempts[0].answer.as_str()), (2, Feedback::Right, "a b&c"));
+}
+
+#[test]
+fn solved_part_is_not_resubmitted() {
+    let server = MockServer::start(|_| Reply::ok(RIGHT));
+    let log = log_path("solved_part_is_not_resubmitted");
+
+    submit(&client(&server), 5, 1, "42", &log).unwrap();
+    let same = submit(&client(&server), 5, 1, "42", &log).unwrap();
+    let different = submit(&client(&server), 5, 1, "43", &log).unwrap();
+    assert_eq!((same.feedback, same.sent), (Feedback::Right, false));
+    assert_eq!((different.feedback, different.sent), (Feedback::AlreadySolved, false));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn rejected_guess_is_not_resubmitted_and_cooldown_is_honored() {
+    let server = MockServer::start(|_| Reply::ok(WRONG));
+    let log = log_path("rejected_guess_is_not_resubmitted");
+
+    let first = submit(&client(&server), 5, 1, "42", &log).unwrap();
+    assert_eq!((first.feedback, first.sent), (Feedback::Wrong, true));
+
+    let again = submit(&client(&server), 5, 1, "42", &log).unwrap();
+    assert_eq!((again.feedback, again.sent), (Feedback::Wrong, false));
+
+    let other = submit(&client(&server), 5, 1, "43", &log).unwrap();
+    assert!(!other.sent);
+    match other.feedback {
+        Feedback::Wait(wait) => assert!(wait > Duration::from_secs(50) && wait <= Duration::from_secs(60)),
+        feedback => panic!("expected a cooldown, got {:?}", feedback),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn too_high_and_too_low_bound_later_guesses() {
+    let server = MockServer::start(|request| Reply::ok(if request.body.ends_with("=100") { TOO_HIGH } else { RIGHT }));
+    let log = log_path("too_high_and_too_low_bound_later_guesses");
+
+    assert_eq!(submit(&client(&server), 5, 1, "100", &log).unwrap().feedback, Feedback::TooHigh);
+    let higher = submit(&client(&server), 5, 1, "150", &log).unwrap();
+    assert_eq!((higher.feedback, higher.sent), (Feedback::TooHigh, false));
+    let lower = submit(&client(&server), 5, 1, "99", &log).unwrap();
+    assert_eq!((lower.feedback, lower.sent), (Feedback::Right, true));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn wait_response_starts_cooldown() {
+    let server = MockServer::start(|_| Reply::ok(TOO_RECENT));
+    let log = log_path("wait_response_starts_cooldown");
+
+    let first = submit(&client(&server), 5, 1, "42", &log).unwrap();
+    assert_eq!((first.feedback, first.sent), (Feedback::Wait(Duration::from_secs(289)), true));
+
+    let second = submit(&client(&server), 5, 1, "42", &log).unwrap();
+    assert!(!second.sent);
+    assert!(matches!(second.feedback, Feedback::Wait(wait) if wait > Duration::from_secs(280)));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn error_status_is_not_logged() {
+    let server = MockServer::start(|_| Reply::status(500, "oops"));
+    let log = log_path("error_status_is_not_logged");
+
+    assert!(submit(&client(&server), 5, 1, "42", &log).is_err());
+    assert!(SubmissionLog::load(&log).unwrap().attempts.is_empty());
+}
+
+#[test]
+fn records_right_answer() {
+    let dir = temp_dir("records_right_answer");
+    let input = dir.join("real.txt");
+
+    record_answer(&input, 2, "336").unwrap();
+    assert_eq!(fs::read_to_string(dir.join("real.txt.answers")).unwrap(), "\n336\n");
+    record_answer(&input, 1, "514579").unwrap();
+    assert_eq!(fs::read_to_string(dir.join("real.txt.answers")).unwrap(), "514579\n336\n");
+}